@@ -1,23 +1,42 @@
 use std::collections::{HashMap};
 use std::sync::{Arc, RwLock};
-use porp::{Being, Entity, World, Transforms, IDManager, Vec3, RenderUpdateData};
+use porp::{Being, BeingId, Entity, World, Transforms, IDManager, Vec3, RenderUpdateData, Camera};
 
 use iso::IBT;
+use iso::selection::{Selection};
+
+const ZOOM_STEP: f32 = 1.1;
 
 pub struct Layer {
     entities: HashMap<u64, Arc<RwLock<Entity>>>,
     position: Vec3,
     render_updates: Arc<RwLock<RenderUpdateData>>,
+    camera: Arc<RwLock<Camera>>,
+    selection: Arc<RwLock<Selection>>,
+    tiles: HashMap<(i32, i32), BeingId>,
 }
 
 impl Layer {
-    pub fn new(manager: Arc<RwLock<IDManager>>, position: Vec3) -> Box<Layer> {
+    pub fn new(position: Vec3, camera: Arc<RwLock<Camera>>, selection: Arc<RwLock<Selection>>, tiles: HashMap<(i32, i32), BeingId>) -> Box<Layer> {
         Box::new(Layer {
             entities: HashMap::new(),
             position: position,
             render_updates: Arc::new(RwLock::new(RenderUpdateData::new())),
+            camera: camera,
+            selection: selection,
+            tiles: tiles,
         })
     }
+
+    fn pick_tile(&self, world: &World<IBT>) -> Option<((i32, i32), BeingId)> {
+        let camera = self.camera.read().expect("Unable to Read Camera in Pick Tile in Layer");
+        let point = match world.pick_plane(&camera, self.position, Vec3::from([0.0, 1.0, 0.0])) {
+            Some(point) => point,
+            None => return None,
+        };
+        let coords = (point[0].floor() as i32, point[2].floor() as i32);
+        self.tiles.get(&coords).map(|id| (coords, *id))
+    }
 }
 
 impl Being<IBT> for Layer {
@@ -29,12 +48,27 @@ impl Being<IBT> for Layer {
         self.render_updates.clone()
     }
 
-    fn tick_prep(&self, delta_time: &f32, world: &World<IBT>, transforms: &Transforms) {
-
+    fn tick_prep(&self, _: &f32, world: &World<IBT>, _: &Transforms) {
+        let hovered = self.pick_tile(world);
+        let mut selection = self.selection.write().expect("Unable to Write Selection in Tick Prep in Layer");
+        selection.set_hovered(hovered);
+        if world.action_just_pressed("select") && !world.is_paused() {
+            selection.set_selected(hovered);
+        }
+        if world.action_just_pressed("pause") {
+            world.set_paused(!world.is_paused());
+        }
     }
 
-    fn tick(&mut self, world: Arc<RwLock<World<IBT>>>, transforms: Arc<RwLock<Transforms>>, manager: Arc<RwLock<IDManager>>) {
+    fn tick(&mut self, world: Arc<RwLock<World<IBT>>>, _: Arc<RwLock<Transforms>>, _: Arc<RwLock<IDManager>>) {
+        let scroll = world.read().expect("Unable to Read World in Tick in Layer").get_mouse_scroll();
+        if scroll[1] != 0.0 {
+            self.camera.write().expect("Unable to Write Camera in Tick in Layer").zoom_by(ZOOM_STEP.powf(scroll[1]));
+        }
+    }
 
+    fn runs_while_paused(&self) -> bool {
+        true
     }
 
     fn get_position(&self) -> Vec3 {
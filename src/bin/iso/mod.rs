@@ -1,9 +1,14 @@
-use porp::{init, Window, World, WindowArgs, Game, Vec3, Being};
+use image::{RgbaImage, Rgba};
+use std::collections::{HashMap};
+use std::sync::{Arc, RwLock};
+use porp::{init, Window, WindowArgs, Game, Being, Vec3, AtlasBuilder, Camera, Binding, MouseButton, KeyCode};
 
 mod tile;
 mod layer;
+mod selection;
 
 mod iso_being_type {
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
     pub enum IsoBeingType {
         Tile,
         Layer,
@@ -15,6 +20,30 @@ mod iso_being_type {
 
 pub use self::iso_being_type::IsoBeingType as IBT;
 
+use self::tile::{Tile};
+use self::layer::{Layer};
+use self::selection::{Selection};
+
+const MAP_SIZE: u32 = 16;
+const SPRITE_SIZE: u32 = 32;
+const SPRITES: [(&'static str, [u8; 4]); 3] = [
+    ("grass", [64, 160, 64, 255]),
+    ("dirt", [128, 96, 64, 255]),
+    ("water", [64, 96, 192, 255]),
+];
+
+fn make_sprite(color: [u8; 4]) -> RgbaImage {
+    let mut image = RgbaImage::new(SPRITE_SIZE, SPRITE_SIZE);
+    for y in 0..SPRITE_SIZE {
+        for x in 0..SPRITE_SIZE {
+            let border = x == 0 || y == 0 || x == SPRITE_SIZE - 1 || y == SPRITE_SIZE - 1;
+            let color = if border { [color[0] / 2, color[1] / 2, color[2] / 2, color[3]] } else { color };
+            image.put_pixel(x, y, Rgba { data: color });
+        }
+    }
+    image
+}
+
 pub fn main() {
     let manager = init();
 
@@ -26,9 +55,53 @@ pub fn main() {
 
     let mut game = Game::<IBT>::new(manager.clone(), thread_count, resolution);
 
-    let world = game.get_world();
+    let mut builder = AtlasBuilder::new(1, 256);
+    for sprite in SPRITES.iter() {
+        builder.add_image(sprite.0, make_sprite(sprite.1)).expect("Unable to Add Sprite in Main");
+    }
+    let atlas = window.upload_atlas(manager.clone(), builder);
+
+    let camera = Arc::new(RwLock::new(Camera::isometric(manager.clone(), MAP_SIZE as f32, resolution)));
+    camera.write().expect("Unable to Write Camera in Main").look_at(Vec3::from([MAP_SIZE as f32 / 2.0, 0.0, MAP_SIZE as f32 / 2.0]), 100.0);
+    game.add_camera(camera.clone());
 
-    let world = world.read().expect("Unable to Read World in Main");
+    let selection = Arc::new(RwLock::new(Selection::new()));
+
+    {
+        let world = game.get_world();
+        let world = world.read().expect("Unable to Read World in Main");
+        let actions = world.get_actions();
+        let mut actions = actions.write().expect("Unable to Write Actions in Main");
+        actions.bind_button("select", Binding::mouse(MouseButton::Left));
+        actions.bind_button("pause", Binding::key(KeyCode::P));
+        drop(actions);
+        let transforms = game.get_transforms();
+        let transforms = transforms.read().expect("Unable to Read Transforms in Main");
+        let view = camera.read().expect("Unable to Read Camera in Main");
+        let mut bases: Vec<Option<Box<Tile>>> = SPRITES.iter().map(|_| None).collect();
+        let mut tiles = HashMap::new();
+        for z in 0..MAP_SIZE {
+            for x in 0..MAP_SIZE {
+                let position = Vec3::from([x as f32, 0.0, z as f32]);
+                let sprite = ((x + z) as usize) % SPRITES.len();
+                let tile = match bases[sprite] {
+                    Some(ref base) => Some(Tile::new_from(manager.clone(), &transforms, position, base)),
+                    None => None,
+                };
+                match tile {
+                    Some(tile) => {
+                        tiles.insert((x as i32, z as i32), world.spawn(tile));
+                    },
+                    None => bases[sprite] = Some(Tile::new(manager.clone(), &transforms, position, &atlas, SPRITES[sprite].0, &view, selection.clone())),
+                }
+            }
+        }
+        for base in bases.into_iter().filter_map(|base| base) {
+            let position = base.get_position();
+            tiles.insert((position[0] as i32, position[2] as i32), world.spawn(base));
+        }
+        world.spawn(Layer::new(Vec3::zero(), camera.clone(), selection.clone(), tiles));
+    }
 
     game.run(&mut window);
 }
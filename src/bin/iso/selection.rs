@@ -0,0 +1,31 @@
+use porp::{BeingId};
+
+pub struct Selection {
+    hovered: Option<((i32, i32), BeingId)>,
+    selected: Option<((i32, i32), BeingId)>,
+}
+
+impl Selection {
+    pub fn new() -> Selection {
+        Selection {
+            hovered: None,
+            selected: None,
+        }
+    }
+
+    pub fn get_hovered(&self) -> Option<((i32, i32), BeingId)> {
+        self.hovered
+    }
+
+    pub fn set_hovered(&mut self, hovered: Option<((i32, i32), BeingId)>) {
+        self.hovered = hovered;
+    }
+
+    pub fn get_selected(&self) -> Option<((i32, i32), BeingId)> {
+        self.selected
+    }
+
+    pub fn set_selected(&mut self, selected: Option<((i32, i32), BeingId)>) {
+        self.selected = selected;
+    }
+}
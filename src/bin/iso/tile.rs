@@ -1,46 +1,77 @@
 use std::collections::{HashMap};
 use std::sync::{Arc, RwLock};
-use porp::{Being, Entity, World, Transforms, IDManager, Vec3, RenderUpdateData, IDType};
+use porp::{Being, BeingId, Entity, World, Transforms, IDManager, Vec3, Mat4, RenderUpdateData, IDType, Atlas, Vertex, DrawMethod, Camera};
 
 use iso::IBT;
+use iso::selection::{Selection};
 
 pub const ENTITY_TILE: u64 = 0;
 
+const HOVER_ELEVATION: f32 = 0.1;
+const SELECT_ELEVATION: f32 = 0.25;
+
 pub struct Tile {
     entities: HashMap<u64, Arc<RwLock<Entity>>>,
     position: Vec3,
     render_updates: Arc<RwLock<RenderUpdateData>>,
+    selection: Arc<RwLock<Selection>>,
+    id: BeingId,
+    elevation: f32,
 }
 
 impl Tile {
-    pub fn new(manager: Arc<RwLock<IDManager>>, position: Vec3) -> Box<Tile> {
-        let mut entities = HashMap::new();
-        entities.insert(ENTITY_TILE, Arc::new(RwLock::new(Entity::new(manager))));
+    pub fn new(manager: Arc<RwLock<IDManager>>, transforms: &Transforms, position: Vec3, atlas: &Atlas, sprite: &str, camera: &Camera, selection: Arc<RwLock<Selection>>) -> Box<Tile> {
+        let entity = Arc::new(RwLock::new(Entity::new(manager)));
+        entity.write().expect("Unable to Write Entity in New in Tile").use_id(atlas.get_texture_id(), IDType::Texture);
+        camera.attach(&entity);
+        set_model(transforms, &entity, position);
         let mut render_updates = RenderUpdateData::new();
+        render_updates.push_vertices(ENTITY_TILE, atlas.map_vertices(sprite, vec!(
+            Vertex::new([0.0, 0.0, 0.0], [0.0, 0.0]),
+            Vertex::new([1.0, 0.0, 0.0], [1.0, 0.0]),
+            Vertex::new([1.0, 0.0, 1.0], [1.0, 1.0]),
+            Vertex::new([0.0, 0.0, 1.0], [0.0, 1.0]),
+        )).expect("Unable to Map Vertices in New in Tile"));
+        render_updates.push_indices(ENTITY_TILE, vec!(0, 1, 2, 2, 3, 0));
+        render_updates.push_draw_method(ENTITY_TILE, DrawMethod::Neither);
+        let mut entities = HashMap::new();
+        entities.insert(ENTITY_TILE, entity);
         Box::new(Tile {
             entities: entities,
             position: position,
             render_updates: Arc::new(RwLock::new(render_updates)),
+            selection: selection,
+            id: 0,
+            elevation: 0.0,
         })
     }
 
-    pub fn new_from(manager: Arc<RwLock<IDManager>>, position: Vec3, base: Box<Tile>) -> Box<Tile> {
+    pub fn new_from(manager: Arc<RwLock<IDManager>>, transforms: &Transforms, position: Vec3, base: &Tile) -> Box<Tile> {
         let mut entities = HashMap::new();
-        let mut render_updates = RenderUpdateData::new();
+        let render_updates = RenderUpdateData::new();
         let their_entities = base.get_entities();
         for entry in their_entities.iter() {
             let mut entity = Entity::new_from(entry.1);
             entity.use_new_id(manager.clone(), IDType::Model);
-            entities.insert(*entry.0, Arc::new(RwLock::new(entity)));
+            let entity = Arc::new(RwLock::new(entity));
+            set_model(transforms, &entity, position);
+            entities.insert(*entry.0, entity);
         }
         Box::new(Tile{
             entities: entities,
             position: position,
             render_updates: Arc::new(RwLock::new(render_updates)),
+            selection: base.selection.clone(),
+            id: 0,
+            elevation: 0.0,
         })
     }
 }
 
+fn set_model(transforms: &Transforms, entity: &Arc<RwLock<Entity>>, position: Vec3) {
+    transforms.set_model_matrix(entity, Mat4::translation_from_vec3(position));
+}
+
 impl Being<IBT> for Tile {
     fn get_entities(&self) -> &HashMap<u64, Arc<RwLock<Entity>>> {
         &self.entities
@@ -50,12 +81,29 @@ impl Being<IBT> for Tile {
         self.render_updates.clone()
     }
 
-    fn tick_prep(&self, delta_time: &f32, world: &World<IBT>, transforms: &Transforms) {
+    fn tick_prep(&self, _: &f32, _: &World<IBT>, _: &Transforms) {
 
     }
 
-    fn tick(&mut self, world: Arc<RwLock<World<IBT>>>, transforms: Arc<RwLock<Transforms>>, manager: Arc<RwLock<IDManager>>) {
-
+    fn tick(&mut self, _: Arc<RwLock<World<IBT>>>, transforms: Arc<RwLock<Transforms>>, _: Arc<RwLock<IDManager>>) {
+        let elevation = {
+            let selection = self.selection.read().expect("Unable to Read Selection in Tick in Tile");
+            if selection.get_selected().map_or(false, |selected| selected.1 == self.id) {
+                SELECT_ELEVATION
+            } else if selection.get_hovered().map_or(false, |hovered| hovered.1 == self.id) {
+                HOVER_ELEVATION
+            } else {
+                0.0
+            }
+        };
+        if elevation != self.elevation {
+            self.elevation = elevation;
+            let transforms = transforms.read().expect("Unable to Read Transforms in Tick in Tile");
+            let position = self.position + Vec3::from([0.0, elevation, 0.0]);
+            for entity in self.entities.values() {
+                set_model(&transforms, entity, position);
+            }
+        }
     }
 
     fn get_position(&self) -> Vec3 {
@@ -65,4 +113,8 @@ impl Being<IBT> for Tile {
     fn get_type(&self) -> IBT {
         IBT::Tile
     }
+
+    fn on_spawn(&mut self, id: BeingId, _: &World<IBT>) {
+        self.id = id;
+    }
 }
@@ -1,4 +1,5 @@
 extern crate porp;
+extern crate image;
 
 mod iso;
 
@@ -0,0 +1,226 @@
+use image::{load_from_memory, ImageError};
+use std::collections::{HashMap};
+use std::collections::hash_map::{DefaultHasher};
+use std::error::{Error as StdError};
+use std::fmt::{Display, Formatter, Error};
+use std::fs::{File, metadata};
+use std::hash::{Hash, Hasher};
+use std::io::{Read};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock, Weak};
+use std::time::{SystemTime};
+use time::{precise_time_s};
+
+use graphics::{Backend, ID, IDType, IDManager, Entity};
+
+#[derive(Debug)]
+pub enum AssetError {
+    Io(PathBuf, io::Error),
+    Decode(PathBuf, ImageError),
+}
+
+impl Display for AssetError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            AssetError::Io(ref path, ref err) => write!(f, "Unable to Read Asset {}: {}", path.display(), err),
+            AssetError::Decode(ref path, ref err) => write!(f, "Unable to Decode Asset {}: {}", path.display(), err),
+        }
+    }
+}
+
+impl StdError for AssetError {
+    fn description(&self) -> &str {
+        match *self {
+            AssetError::Io(_, _) => "Unable to Read Asset",
+            AssetError::Decode(_, _) => "Unable to Decode Asset",
+        }
+    }
+}
+
+struct TextureAsset {
+    id: ID,
+    hash: u64,
+    modified: Option<SystemTime>,
+    users: Vec<Weak<RwLock<Entity>>>,
+}
+
+pub struct AssetManager {
+    textures: HashMap<PathBuf, TextureAsset>,
+    hashes: HashMap<u64, ID>,
+    managers: HashMap<ID, Arc<RwLock<IDManager>>>,
+    watching: bool,
+    watch_interval: f64,
+    last_check: f64,
+}
+
+impl AssetManager {
+    pub fn new() -> AssetManager {
+        AssetManager {
+            textures: HashMap::new(),
+            hashes: HashMap::new(),
+            managers: HashMap::new(),
+            watching: false,
+            watch_interval: 1.0,
+            last_check: 0.0,
+        }
+    }
+
+    pub fn set_watching(&mut self, watching: bool) {
+        self.watching = watching;
+    }
+
+    pub fn set_watch_interval(&mut self, watch_interval: f64) {
+        self.watch_interval = watch_interval;
+    }
+
+    pub fn get_texture_id(&self, path: &Path) -> Option<ID> {
+        self.textures.get(path).map(|asset| asset.id)
+    }
+
+    pub fn load_texture(&mut self, backend: &mut Backend, manager: Arc<RwLock<IDManager>>, path: &Path) -> Result<ID, AssetError> {
+        if let Some(asset) = self.textures.get(path) {
+            return Ok(asset.id);
+        }
+        let data = read_file(path)?;
+        let hash = hash_bytes(&data);
+        let id = match self.hashes.get(&hash) {
+            Some(id) => {
+                manager.write().expect("Unable to Write Manager in Load Texture in Asset Manager").retain(IDType::Texture, *id);
+                *id
+            },
+            None => {
+                let texture = load_from_memory(&data).map_err(|err| AssetError::Decode(path.to_path_buf(), err))?.to_rgba();
+                let id = ID::new(manager.clone(), IDType::Texture);
+                backend.set_texture(id, texture);
+                self.hashes.insert(hash, id);
+                self.managers.insert(id, manager);
+                id
+            },
+        };
+        self.textures.insert(path.to_path_buf(), TextureAsset {
+            id: id,
+            hash: hash,
+            modified: modified_time(path),
+            users: vec!(),
+        });
+        Ok(id)
+    }
+
+    pub fn add_user(&mut self, path: &Path, entity: &Arc<RwLock<Entity>>) {
+        if let Some(asset) = self.textures.get_mut(path) {
+            asset.users.retain(|user| user.upgrade().is_some());
+            asset.users.push(Arc::downgrade(entity));
+        }
+    }
+
+    pub fn unload_texture(&mut self, path: &Path) {
+        if let Some(asset) = self.textures.remove(path) {
+            let shared = self.textures.values().any(|other| other.id == asset.id);
+            if !shared {
+                self.hashes.remove(&asset.hash);
+            }
+            let manager = if shared {
+                self.managers.get(&asset.id).cloned()
+            } else {
+                self.managers.remove(&asset.id)
+            };
+            if let Some(manager) = manager {
+                manager.write().expect("Unable to Write Manager in Unload Texture in Asset Manager").release(IDType::Texture, asset.id);
+            }
+        }
+    }
+
+    pub fn reload_changed(&mut self, backend: &mut Backend) -> Vec<AssetError> {
+        let mut errors = vec!();
+        if !self.watching {
+            return errors;
+        }
+        let now = precise_time_s();
+        if now - self.last_check < self.watch_interval {
+            return errors;
+        }
+        self.last_check = now;
+        let paths: Vec<PathBuf> = self.textures.keys().cloned().collect();
+        for path in paths {
+            if let Err(err) = self.reload_texture(backend, &path) {
+                errors.push(err);
+            }
+        }
+        errors
+    }
+
+    fn reload_texture(&mut self, backend: &mut Backend, path: &Path) -> Result<(), AssetError> {
+        let (old_id, old_hash) = {
+            let asset = self.textures.get_mut(path).expect("Unable to Get Asset in Reload Texture in Asset Manager");
+            let modified = modified_time(path);
+            if modified == asset.modified {
+                return Ok(());
+            }
+            asset.modified = modified;
+            (asset.id, asset.hash)
+        };
+        let data = read_file(path)?;
+        let hash = hash_bytes(&data);
+        if hash == old_hash {
+            return Ok(());
+        }
+        let texture = load_from_memory(&data).map_err(|err| AssetError::Decode(path.to_path_buf(), err))?.to_rgba();
+        let shared = self.textures.iter().any(|(other_path, other)| other.id == old_id && other_path.as_path() != path);
+        if !shared && self.hashes.get(&old_hash) == Some(&old_id) {
+            self.hashes.remove(&old_hash);
+        }
+        let manager = self.managers.get(&old_id).cloned().expect("Unable to Get Manager in Reload Texture in Asset Manager");
+        let id = match self.hashes.get(&hash).cloned() {
+            Some(id) => {
+                manager.write().expect("Unable to Write Manager in Reload Texture in Asset Manager").retain(IDType::Texture, id);
+                id
+            },
+            None if shared => {
+                let id = ID::new(manager.clone(), IDType::Texture);
+                backend.set_texture(id, texture);
+                self.hashes.insert(hash, id);
+                self.managers.insert(id, manager.clone());
+                id
+            },
+            None => {
+                backend.set_texture(old_id, texture);
+                self.hashes.insert(hash, old_id);
+                old_id
+            },
+        };
+        let asset = self.textures.get_mut(path).expect("Unable to Get Asset in Reload Texture in Asset Manager");
+        asset.id = id;
+        asset.hash = hash;
+        if id != old_id {
+            asset.users.retain(|user| user.upgrade().is_some());
+            for user in asset.users.iter() {
+                if let Some(entity) = user.upgrade() {
+                    entity.write().expect("Unable to Write Entity in Reload Texture in Asset Manager").use_id(id, IDType::Texture);
+                }
+            }
+            if !shared {
+                self.managers.remove(&old_id);
+            }
+            manager.write().expect("Unable to Write Manager in Reload Texture in Asset Manager").release(IDType::Texture, old_id);
+        }
+        Ok(())
+    }
+}
+
+pub fn read_file(path: &Path) -> Result<Vec<u8>, AssetError> {
+    let mut file = File::open(path).map_err(|err| AssetError::Io(path.to_path_buf(), err))?;
+    let mut data = vec!();
+    file.read_to_end(&mut data).map_err(|err| AssetError::Io(path.to_path_buf(), err))?;
+    Ok(data)
+}
+
+fn hash_bytes(data: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    data.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
@@ -0,0 +1,254 @@
+use image::{RgbaImage, load_from_memory, ImageError};
+use std::collections::{HashMap};
+use std::error::{Error as StdError};
+use std::fmt::{Display, Formatter, Error};
+use std::path::{Path};
+use std::sync::{Arc, RwLock};
+
+use math::{Vec2};
+use graphics::{ID, IDType, IDManager, Vertex, AssetError, read_file};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct UvRect {
+    min: Vec2,
+    max: Vec2,
+}
+
+impl UvRect {
+    pub fn new(min: Vec2, max: Vec2) -> UvRect {
+        UvRect {
+            min: min,
+            max: max,
+        }
+    }
+
+    pub fn get_min(&self) -> Vec2 {
+        self.min
+    }
+
+    pub fn get_max(&self) -> Vec2 {
+        self.max
+    }
+
+    pub fn map(&self, tex_coord: Vec2) -> Vec2 {
+        self.min + (self.max - self.min) * tex_coord
+    }
+}
+
+#[derive(Debug)]
+pub enum AtlasError {
+    TooWide(String, u32, u32),
+    Image(ImageError),
+    Asset(AssetError),
+}
+
+impl Display for AtlasError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            AtlasError::TooWide(ref name, width, max_width) => write!(f, "Unable to Fit Image {} of Width {} in Atlas of Width {}", name, width, max_width),
+            AtlasError::Image(ref err) => write!(f, "Unable to Decode Atlas Image: {}", err),
+            AtlasError::Asset(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl StdError for AtlasError {
+    fn description(&self) -> &str {
+        match *self {
+            AtlasError::TooWide(_, _, _) => "Unable to Fit Image in Atlas",
+            AtlasError::Image(_) => "Unable to Decode Atlas Image",
+            AtlasError::Asset(_) => "Unable to Load Atlas Image",
+        }
+    }
+}
+
+impl From<ImageError> for AtlasError {
+    fn from(err: ImageError) -> AtlasError {
+        AtlasError::Image(err)
+    }
+}
+
+impl From<AssetError> for AtlasError {
+    fn from(err: AssetError) -> AtlasError {
+        AtlasError::Asset(err)
+    }
+}
+
+pub struct AtlasBuilder {
+    images: Vec<(String, RgbaImage)>,
+    padding: u32,
+    max_width: u32,
+}
+
+impl AtlasBuilder {
+    pub fn new(padding: u32, max_width: u32) -> AtlasBuilder {
+        AtlasBuilder {
+            images: vec!(),
+            padding: padding,
+            max_width: max_width,
+        }
+    }
+
+    pub fn add_image(&mut self, name: &str, image: RgbaImage) -> Result<(), AtlasError> {
+        if image.width() + 2 * self.padding > self.max_width {
+            return Err(AtlasError::TooWide(name.to_string(), image.width(), self.max_width));
+        }
+        self.images.push((name.to_string(), image));
+        Ok(())
+    }
+
+    pub fn add_bytes(&mut self, name: &str, data: &[u8]) -> Result<(), AtlasError> {
+        let image = load_from_memory(data)?.to_rgba();
+        self.add_image(name, image)
+    }
+
+    pub fn add_path(&mut self, name: &str, path: &Path) -> Result<(), AtlasError> {
+        let data = read_file(path)?;
+        let image = load_from_memory(&data).map_err(|err| AssetError::Decode(path.to_path_buf(), err))?.to_rgba();
+        self.add_image(name, image)
+    }
+
+    pub fn build(mut self) -> (RgbaImage, HashMap<String, UvRect>) {
+        self.images.sort_by(|a, b| b.1.height().cmp(&a.1.height()));
+
+        let mut placements: Vec<(u32, u32)> = vec!();
+        let mut x = self.padding;
+        let mut y = self.padding;
+        let mut shelf_height = 0;
+        let mut width = 0;
+        for entry in self.images.iter() {
+            let (image_width, image_height) = entry.1.dimensions();
+            if x > self.padding && x + image_width + self.padding > self.max_width {
+                x = self.padding;
+                y += shelf_height + self.padding;
+                shelf_height = 0;
+            }
+            placements.push((x, y));
+            x += image_width + self.padding;
+            if x > width {
+                width = x;
+            }
+            if image_height > shelf_height {
+                shelf_height = image_height;
+            }
+        }
+        let height = y + shelf_height + self.padding;
+        let width = if width == 0 { 1 } else { width };
+        let height = if height == 0 { 1 } else { height };
+
+        let mut atlas = RgbaImage::new(width, height);
+        let mut regions = HashMap::new();
+        for (entry, placement) in self.images.iter().zip(placements.iter()) {
+            let (image_width, image_height) = entry.1.dimensions();
+            for image_y in 0..image_height {
+                for image_x in 0..image_width {
+                    atlas.put_pixel(placement.0 + image_x, placement.1 + image_y, *entry.1.get_pixel(image_x, image_y));
+                }
+            }
+            regions.insert(entry.0.clone(), UvRect::new(
+                Vec2::from([placement.0 as f32 / width as f32, 1.0 - (placement.1 + image_height) as f32 / height as f32]),
+                Vec2::from([(placement.0 + image_width) as f32 / width as f32, 1.0 - placement.1 as f32 / height as f32]),
+            ));
+        }
+        (atlas, regions)
+    }
+}
+
+pub struct Atlas {
+    manager: Arc<RwLock<IDManager>>,
+    texture_id: ID,
+    regions: HashMap<String, UvRect>,
+}
+
+impl Atlas {
+    pub fn new(manager: Arc<RwLock<IDManager>>, texture_id: ID, regions: HashMap<String, UvRect>) -> Atlas {
+        Atlas {
+            manager: manager,
+            texture_id: texture_id,
+            regions: regions,
+        }
+    }
+
+    pub fn get_texture_id(&self) -> ID {
+        self.texture_id
+    }
+
+    pub fn get_region(&self, name: &str) -> Option<UvRect> {
+        self.regions.get(name).cloned()
+    }
+
+    pub fn map_vertices(&self, name: &str, vertices: Vec<Vertex>) -> Option<Vec<Vertex>> {
+        let region = match self.get_region(name) {
+            Some(region) => region,
+            None => return None,
+        };
+        Some(vertices.into_iter().map(|vertex| {
+            Vertex::new(vertex.get_position(), region.map(Vec2::from(vertex.get_tex_coord())).get_vals())
+        }).collect())
+    }
+}
+
+impl Drop for Atlas {
+    fn drop(&mut self) {
+        self.manager.write().expect("Unable to Write Manager in Drop in Atlas").release(IDType::Texture, self.texture_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{RgbaImage, Rgba};
+    use std::sync::{Arc, RwLock};
+
+    use math::{Vec2};
+    use graphics::{ID, IDType, IDManager, AtlasBuilder, AtlasError, Atlas, Vertex};
+
+    fn image(width: u32, height: u32, color: [u8; 4]) -> RgbaImage {
+        let mut image = RgbaImage::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                image.put_pixel(x, y, Rgba { data: color });
+            }
+        }
+        image
+    }
+
+    #[test]
+    fn images_wider_than_the_atlas_are_rejected() {
+        let mut builder = AtlasBuilder::new(1, 16);
+        assert!(builder.add_image("fits", image(14, 2, [0; 4])).is_ok());
+        match builder.add_image("wide", image(15, 2, [0; 4])) {
+            Err(AtlasError::TooWide(ref name, 15, 16)) => assert_eq!(name, "wide"),
+            _ => panic!("Expected Too Wide"),
+        }
+    }
+
+    #[test]
+    fn build_packs_rows_within_max_width() {
+        let mut builder = AtlasBuilder::new(1, 16);
+        builder.add_image("red", image(6, 4, [255, 0, 0, 255])).expect("Unable to Add Red");
+        builder.add_image("green", image(6, 2, [0, 255, 0, 255])).expect("Unable to Add Green");
+        builder.add_image("blue", image(6, 2, [0, 0, 255, 255])).expect("Unable to Add Blue");
+        let (atlas, regions) = builder.build();
+        assert!(atlas.width() <= 16);
+        assert_eq!(regions.len(), 3);
+        assert_eq!(atlas.get_pixel(1, 1).data, [255, 0, 0, 255]);
+        for region in regions.values() {
+            assert!(region.get_min()[0] >= 0.0 && region.get_max()[0] <= 1.0);
+            assert!(region.get_min()[1] >= 0.0 && region.get_max()[1] <= 1.0);
+        }
+    }
+
+    #[test]
+    fn map_vertices_returns_none_for_unknown_sprites() {
+        let manager = Arc::new(RwLock::new(IDManager::new()));
+        let mut builder = AtlasBuilder::new(0, 8);
+        builder.add_image("known", image(2, 2, [0; 4])).expect("Unable to Add Known");
+        let (_, regions) = builder.build();
+        let atlas = Atlas::new(manager.clone(), ID::new(manager.clone(), IDType::Texture), regions);
+        let vertices = vec!(Vertex::new([0.0, 0.0, 0.0], [0.0, 0.0]), Vertex::new([1.0, 1.0, 0.0], [1.0, 1.0]));
+        assert!(atlas.map_vertices("missing", vertices.clone()).is_none());
+        let mapped = atlas.map_vertices("known", vertices).expect("Unable to Map Known");
+        assert_eq!(Vec2::from(mapped[0].get_tex_coord()), Vec2::zero());
+        assert_eq!(Vec2::from(mapped[1].get_tex_coord()), Vec2::one());
+    }
+}
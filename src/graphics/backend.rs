@@ -0,0 +1,103 @@
+use glium::glutin::Event as WindowEvent;
+use glium::{DrawParameters};
+use image::{RgbaImage};
+
+use math::{Mat4, Vec2};
+use utils::{CursorState};
+use graphics::{Entity, Vertex, Index, ID, IDType};
+
+pub trait Backend {
+    fn get_resolution(&self) -> Vec2;
+    fn poll_events(&mut self) -> Vec<WindowEvent>;
+    fn set_cursor_state(&mut self, state: CursorState) -> Result<(), String>;
+    fn set_vertices(&mut self, id: ID, vertices: Vec<Vertex>);
+    fn set_indices(&mut self, id: ID, indices: Vec<Index>);
+    fn set_texture(&mut self, id: ID, texture: RgbaImage);
+    fn set_draw_parameters(&mut self, id: ID, draw_parameters: DrawParameters<'static>);
+    fn release(&mut self, id_type: IDType, id: ID);
+    fn begin_frame(&mut self);
+    fn draw(&mut self, draw_call: DrawCall);
+    fn end_frame(&mut self);
+}
+
+#[derive(Copy, Clone, Eq, Hash, PartialEq, Ord, PartialOrd)]
+pub struct BatchKey {
+    vertex_id: ID,
+    index_id: ID,
+    texture_id: ID,
+    draw_parameters_id: ID,
+    perspective_id: ID,
+    view_id: ID,
+}
+
+impl BatchKey {
+    pub fn new(entity: &Entity) -> BatchKey {
+        BatchKey {
+            vertex_id: entity.get_vertex_id(),
+            index_id: entity.get_index_id(),
+            texture_id: entity.get_texture_id(),
+            draw_parameters_id: entity.get_draw_parameters_id(),
+            perspective_id: entity.get_perspective_id(),
+            view_id: entity.get_view_id(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct DrawCall {
+    key: BatchKey,
+    perspective: Mat4,
+    view: Mat4,
+    models: Vec<Mat4>,
+}
+
+impl DrawCall {
+    pub fn new(key: BatchKey, perspective: Mat4, view: Mat4) -> DrawCall {
+        DrawCall {
+            key: key,
+            perspective: perspective,
+            view: view,
+            models: vec!(),
+        }
+    }
+
+    pub fn get_key(&self) -> BatchKey {
+        self.key
+    }
+
+    pub fn push_model(&mut self, model: Mat4) {
+        self.models.push(model);
+    }
+
+    pub fn get_vertex_id(&self) -> ID {
+        self.key.vertex_id
+    }
+
+    pub fn get_index_id(&self) -> ID {
+        self.key.index_id
+    }
+
+    pub fn get_texture_id(&self) -> ID {
+        self.key.texture_id
+    }
+
+    pub fn get_draw_parameters_id(&self) -> ID {
+        self.key.draw_parameters_id
+    }
+
+    pub fn get_perspective(&self) -> Mat4 {
+        self.perspective
+    }
+
+    pub fn get_view(&self) -> Mat4 {
+        self.view
+    }
+
+    pub fn get_models(&self) -> &Vec<Mat4> {
+        &self.models
+    }
+
+    pub fn get_instance_count(&self) -> usize {
+        self.models.len()
+    }
+}
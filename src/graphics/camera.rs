@@ -0,0 +1,196 @@
+use std::f32::consts::{PI};
+use std::sync::{Arc, RwLock};
+
+use math::{Mat4, Vec2, Vec3, Vec4, Ray, Frustum};
+use graphics::{Entity, IDType, IDManager, Transforms};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Projection {
+    Perspective(f32, f32, f32),
+    Orthographic(f32, f32, f32),
+}
+
+pub struct Camera {
+    entity: Arc<RwLock<Entity>>,
+    projection: Projection,
+    pitch: f32,
+    yaw: f32,
+    position: Vec3,
+    zoom: f32,
+    resolution: Vec2,
+    dirty: bool,
+}
+
+impl Camera {
+    pub fn new(manager: Arc<RwLock<IDManager>>, projection: Projection, resolution: Vec2) -> Camera {
+        Camera {
+            entity: Arc::new(RwLock::new(Entity::new(manager))),
+            projection: projection,
+            pitch: 0.0,
+            yaw: 0.0,
+            position: Vec3::zero(),
+            zoom: 1.0,
+            resolution: resolution,
+            dirty: true,
+        }
+    }
+
+    pub fn perspective(manager: Arc<RwLock<IDManager>>, near: f32, far: f32, field_of_view: f32, resolution: Vec2) -> Camera {
+        Camera::new(manager, Projection::Perspective(near, far, field_of_view), resolution)
+    }
+
+    pub fn orthographic(manager: Arc<RwLock<IDManager>>, near: f32, far: f32, height: f32, resolution: Vec2) -> Camera {
+        Camera::new(manager, Projection::Orthographic(near, far, height), resolution)
+    }
+
+    pub fn isometric(manager: Arc<RwLock<IDManager>>, height: f32, resolution: Vec2) -> Camera {
+        let mut camera = Camera::orthographic(manager, 0.1, 1000.0, height, resolution);
+        camera.set_pitch(-(1.0 / 2.0f32.sqrt()).atan());
+        camera.set_yaw_deg(45.0);
+        camera
+    }
+
+    pub fn attach(&self, entity: &Arc<RwLock<Entity>>) {
+        let mut entity = entity.write().expect("Unable to Write Entity in Attach in Camera");
+        entity.use_old_id(&self.entity, IDType::Perspective);
+        entity.use_old_id(&self.entity, IDType::View);
+    }
+
+    pub fn get_entity(&self) -> Arc<RwLock<Entity>> {
+        self.entity.clone()
+    }
+
+    pub fn get_projection(&self) -> Projection {
+        self.projection
+    }
+
+    pub fn set_projection(&mut self, projection: Projection) {
+        self.projection = projection;
+        self.dirty = true;
+    }
+
+    pub fn get_pitch(&self) -> f32 {
+        self.pitch
+    }
+
+    pub fn set_pitch(&mut self, pitch: f32) {
+        self.pitch = pitch;
+        self.dirty = true;
+    }
+
+    pub fn set_pitch_deg(&mut self, pitch: f32) {
+        self.set_pitch(pitch * PI / 180.0);
+    }
+
+    pub fn get_yaw(&self) -> f32 {
+        self.yaw
+    }
+
+    pub fn set_yaw(&mut self, yaw: f32) {
+        self.yaw = yaw;
+        self.dirty = true;
+    }
+
+    pub fn set_yaw_deg(&mut self, yaw: f32) {
+        self.set_yaw(yaw * PI / 180.0);
+    }
+
+    pub fn get_position(&self) -> Vec3 {
+        self.position
+    }
+
+    pub fn set_position(&mut self, position: Vec3) {
+        self.position = position;
+        self.dirty = true;
+    }
+
+    pub fn get_zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom;
+        self.dirty = true;
+    }
+
+    pub fn zoom_by(&mut self, factor: f32) {
+        let zoom = self.zoom * factor;
+        self.set_zoom(zoom);
+    }
+
+    pub fn get_forward(&self) -> Vec3 {
+        -Vec3::from(self.get_view_matrix()[2])
+    }
+
+    pub fn look_at(&mut self, target: Vec3, distance: f32) {
+        let position = target - self.get_forward() * distance;
+        self.set_position(position);
+    }
+
+    pub fn pan(&mut self, offset: Vec2) {
+        let view = self.get_view_matrix();
+        let right = Vec3::from(view[0]);
+        let up = Vec3::from(view[1]);
+        let position = self.position + (right * offset[0] + up * offset[1]) * (1.0 / self.zoom);
+        self.set_position(position);
+    }
+
+    pub fn get_resolution(&self) -> Vec2 {
+        self.resolution
+    }
+
+    pub fn get_aspect_ratio(&self) -> f32 {
+        self.resolution[0] / self.resolution[1]
+    }
+
+    pub fn get_perspective_matrix(&self) -> Mat4 {
+        match self.projection {
+            Projection::Perspective(near, far, field_of_view) => Mat4::perspective(near, far, field_of_view / self.zoom, self.get_aspect_ratio()),
+            Projection::Orthographic(near, far, height) => Mat4::orthographic_size(near, far, height / self.zoom, self.get_aspect_ratio()),
+        }
+    }
+
+    pub fn get_view_matrix(&self) -> Mat4 {
+        Mat4::view(self.pitch, self.yaw, self.position)
+    }
+
+    pub fn get_frustum(&self) -> Frustum {
+        Frustum::from_matrix(&(self.get_perspective_matrix() * self.get_view_matrix()))
+    }
+
+    pub fn screen_to_ndc(&self, screen: Vec2) -> Vec2 {
+        Vec2::from([2.0 * screen[0] / self.resolution[0] - 1.0, 1.0 - 2.0 * screen[1] / self.resolution[1]])
+    }
+
+    pub fn ndc_to_world(&self, ndc: Vec2, depth: f32) -> Option<Vec3> {
+        let inverse = match (self.get_view_matrix().try_inverse(), self.get_perspective_matrix().try_inverse()) {
+            (Some(view), Some(perspective)) => view * perspective,
+            _ => return None,
+        };
+        let point = inverse * Vec4::from([ndc[0], ndc[1], depth, 1.0]);
+        Some(Vec3::from(point) * (1.0 / point[3]))
+    }
+
+    pub fn screen_to_ray(&self, screen: Vec2) -> Option<Ray> {
+        let ndc = self.screen_to_ndc(screen);
+        match (self.ndc_to_world(ndc, -1.0), self.ndc_to_world(ndc, 1.0)) {
+            (Some(near), Some(far)) => Some(Ray::new(near, far - near)),
+            _ => None,
+        }
+    }
+
+    pub fn update(&mut self, transforms: &Transforms, resolution: Vec2) {
+        if resolution != self.resolution {
+            self.resolution = resolution;
+            self.dirty = true;
+        }
+        if !self.dirty {
+            return;
+        }
+        let perspective = self.get_perspective_matrix();
+        let view = self.get_view_matrix();
+        transforms.set_perspective_matrix(&self.entity, perspective);
+        transforms.set_view_matrix(&self.entity, view);
+        self.dirty = false;
+    }
+}
@@ -0,0 +1,193 @@
+use glium::backend::glutin_backend::{GlutinFacade};
+use glium::glutin::Event as WindowEvent;
+use glium::texture::texture2d::{Texture2d};
+use glium::texture::{RawImage2d};
+use glium::glutin::{WindowBuilder, get_primary_monitor};
+use glium::{Surface, DisplayBuild, Program, VertexBuffer, IndexBuffer, DrawParameters};
+use glium;
+use image::{RgbaImage};
+use std::collections::{HashMap};
+
+use math::{Vec2};
+use utils::{CursorState};
+use graphics::{Backend, DrawCall, Vertex, Index, ID, IDType, WindowArgs};
+
+#[derive(Copy, Clone)]
+struct Instance {
+    model: [[f32; 4]; 4],
+}
+
+implement_vertex!(Instance, model);
+
+pub struct GliumBackend {
+    facade: GlutinFacade,
+    program: Program,
+    texture_buffers: HashMap<ID, Texture2d>,
+    vertex_buffers: HashMap<ID, VertexBuffer<Vertex>>,
+    index_buffers: HashMap<ID, IndexBuffer<Index>>,
+    draw_parameters: HashMap<ID, DrawParameters<'static>>,
+    instance_buffer: Option<VertexBuffer<Instance>>,
+    frame: Option<glium::Frame>,
+    resolution: (u32, u32),
+}
+
+impl GliumBackend {
+    pub fn new(args: WindowArgs) -> GliumBackend {
+        let vertex_shader_src = r#"
+            #version 140
+
+            in vec3 position;
+            in vec2 tex_coord;
+            in mat4 model;
+            uniform mat4 perspective;
+            uniform mat4 view;
+
+            out vec2 v_tex_coord;
+
+            void main() {
+                v_tex_coord = tex_coord;
+                gl_Position = perspective * view * model * vec4(position, 1.0);
+            }
+        "#;
+
+        let fragment_shader_src = r#"
+            #version 140
+
+            in vec2 v_tex_coord;
+
+            out vec4 color;
+
+            uniform sampler2D tex;
+
+            void main() {
+                color = texture(tex, v_tex_coord);
+            }
+        "#;
+
+        let resolution: (u32, u32) = get_primary_monitor().get_dimensions();
+
+        let facade = match args {
+            WindowArgs::Windowed(width, height, title) => {
+                let facade = WindowBuilder::new()
+                    .with_title(title)
+                    .with_dimensions(width, height)
+                    .with_decorations(true)
+                    .with_depth_buffer(24)
+                    .with_vsync()
+                    .build_glium()
+                    .expect("Unable to make Facade");
+                facade.get_window()
+                    .expect("Unable to find the Window")
+                    .set_position(((resolution.0 - width) / 2) as i32, ((resolution.1 - height) / 2) as i32);
+                facade
+            },
+            WindowArgs::Borderless(title) => {
+                let facade = WindowBuilder::new()
+                    .with_title(title)
+                    .with_dimensions(resolution.0, resolution.1)
+                    .with_decorations(false)
+                    .with_depth_buffer(24)
+                    .with_vsync()
+                    .build_glium()
+                    .expect("Unable to make Facade");
+                facade.get_window()
+                    .expect("Unable to find Window")
+                    .set_position(0, 0);
+                facade
+            },
+        };
+        GliumBackend {
+            program: Program::from_source(&facade, vertex_shader_src, fragment_shader_src, None).expect("Unable to make Shader Program"),
+            facade: facade,
+            texture_buffers: HashMap::new(),
+            vertex_buffers: HashMap::new(),
+            index_buffers: HashMap::new(),
+            draw_parameters: HashMap::new(),
+            instance_buffer: None,
+            frame: None,
+            resolution: resolution,
+        }
+    }
+}
+
+impl Backend for GliumBackend {
+    fn get_resolution(&self) -> Vec2 {
+        Vec2::from([self.resolution.0 as f32, self.resolution.1 as f32])
+    }
+
+    fn poll_events(&mut self) -> Vec<WindowEvent> {
+        self.facade.poll_events().collect()
+    }
+
+    fn set_cursor_state(&mut self, state: CursorState) -> Result<(), String> {
+        self.facade.get_window().expect("Unable to find the Window").set_cursor_state(state)
+    }
+
+    fn set_vertices(&mut self, id: ID, vertices: Vec<Vertex>) {
+        self.vertex_buffers.insert(id, VertexBuffer::new(&self.facade, &vertices).expect("Failed to Create Vertex Buffer"));
+    }
+
+    fn set_indices(&mut self, id: ID, indices: Vec<Index>) {
+        self.index_buffers.insert(id, IndexBuffer::new(&self.facade, glium::index::PrimitiveType::TrianglesList, &indices).expect("Failed to Create Index Buffer"));
+    }
+
+    fn set_texture(&mut self, id: ID, texture: RgbaImage) {
+        let dimensions = texture.dimensions();
+        self.texture_buffers.insert(id, Texture2d::new(&self.facade, RawImage2d::from_raw_rgba_reversed(texture.into_raw(), dimensions)).expect("Unable to make Texture"));
+    }
+
+    fn set_draw_parameters(&mut self, id: ID, draw_parameters: DrawParameters<'static>) {
+        self.draw_parameters.insert(id, draw_parameters);
+    }
+
+    fn release(&mut self, id_type: IDType, id: ID) {
+        match id_type {
+            IDType::Vertex => {
+                self.vertex_buffers.remove(&id);
+            },
+            IDType::Index => {
+                self.index_buffers.remove(&id);
+            },
+            IDType::Texture => {
+                self.texture_buffers.remove(&id);
+            },
+            IDType::DrawParameter => {
+                self.draw_parameters.remove(&id);
+            },
+            _ => (),
+        }
+    }
+
+    fn begin_frame(&mut self) {
+        let mut frame = self.facade.draw();
+        frame.clear_color_and_depth((0.0, 0.0, 0.0, 1.0), 1.0);
+        self.frame = Some(frame);
+    }
+
+    fn draw(&mut self, draw_call: DrawCall) {
+        let instances: Vec<Instance> = draw_call.get_models().iter().map(|model| Instance { model: model.transpose().get_vals() }).collect();
+        let capacity = self.instance_buffer.as_ref().map_or(0, |buffer| buffer.len());
+        if capacity < instances.len() {
+            self.instance_buffer = Some(VertexBuffer::empty_dynamic(&self.facade, instances.len().next_power_of_two()).expect("Failed to Create Instance Buffer"));
+        }
+        let instance_buffer = self.instance_buffer.as_ref().expect("Unable to Get Instance Buffer in Draw in Glium Backend");
+        let instance_slice = instance_buffer.slice(0..instances.len()).expect("Unable to Slice Instance Buffer in Draw in Glium Backend");
+        instance_slice.write(&instances);
+        let frame = self.frame.as_mut().expect("Unable to Get Frame in Draw in Glium Backend");
+        frame.draw(
+            (self.vertex_buffers.get(&draw_call.get_vertex_id()).expect("Unable to Get Vertex Buffer in Draw"), instance_slice.per_instance().expect("Unable to Draw Instanced")),
+            self.index_buffers.get(&draw_call.get_index_id()).expect("Unable to Get Index Buffer in Draw"),
+            &self.program,
+            &uniform!(
+                tex: self.texture_buffers.get(&draw_call.get_texture_id()).expect("Unable to Get Texture Buffer in Draw"),
+                perspective: draw_call.get_perspective(),
+                view: draw_call.get_view(),
+            ),
+            self.draw_parameters.get(&draw_call.get_draw_parameters_id()).expect("Unable to Get Draw Parameter in Draw"))
+            .expect("Unable to draw Batch");
+    }
+
+    fn end_frame(&mut self) {
+        self.frame.take().expect("Unable to Take Frame in End Frame in Glium Backend").finish().expect("Unable to Finish Frame");
+    }
+}
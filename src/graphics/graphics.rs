@@ -1,131 +1,96 @@
-use glium::backend::glutin_backend::{GlutinFacade, PollEventsIter};
-use glium::texture::texture2d::{Texture2d};
-use glium::texture::{RawImage2d};
-use glium::glutin::{WindowBuilder, get_primary_monitor};
-use glium::{Surface, DisplayBuild, Program, VertexBuffer, IndexBuffer, DrawParameters};
+use glium::glutin::Event as WindowEvent;
+use glium::{DrawParameters};
 use glium;
 use image::{load_from_memory};
 use std::collections::{HashMap};
+use std::path::{Path};
 use std::sync::{Arc, RwLock};
 
 use math::{Mat4, Vec2, Vec3, Vec4};
-use graphics::{ID, IDType, IDManager};
+use graphics::{ID, IDType, IDManager, Backend, BatchKey, DrawCall, GliumBackend, AssetManager, AssetError, AtlasBuilder, Atlas};
+use utils::{CursorState};
 
 pub type Index = u32;
 
 pub struct Window {
-    facade: GlutinFacade,
-    program: Program,
-    texture_buffers: HashMap<ID, Texture2d>,
-    vertex_buffers: HashMap<ID, VertexBuffer<Vertex>>,
-    index_buffers: HashMap<ID, IndexBuffer<Index>>,
-    draw_parameters: HashMap<ID, DrawParameters<'static>>,
-    resolution: (u32, u32),
+    backend: Box<Backend>,
+    assets: AssetManager,
 }
 
 impl Window {
     pub fn new(args: WindowArgs) -> Window {
-        let vertex_shader_src = r#"
-            #version 140
-
-            in vec3 position;
-            in vec2 tex_coord;
-            uniform mat4 perspective;
-            uniform mat4 view;
-            uniform mat4 model;
-
-            out vec2 v_tex_coord;
-
-            void main() {
-                v_tex_coord = tex_coord;
-                gl_Position = perspective * view * model * vec4(position, 1.0);
-            }
-        "#;
-
-        let fragment_shader_src = r#"
-            #version 140
-
-            in vec2 v_tex_coord;
-
-            out vec4 color;
-
-            uniform sampler2D tex;
+        Window::from_backend(Box::new(GliumBackend::new(args)))
+    }
 
-            void main() {
-                color = texture(tex, v_tex_coord);
-            }
-        "#;
-
-        let resolution: (u32, u32) = get_primary_monitor().get_dimensions();
-
-        let facade = match args {
-            WindowArgs::Windowed(width, height, title) => {
-                let facade = WindowBuilder::new()
-                    .with_title(title)
-                    .with_dimensions(width, height)
-                    .with_decorations(true)
-                    .with_depth_buffer(24)
-                    .with_vsync()
-                    .build_glium()
-                    .expect("Unable to make Facade");
-                facade.get_window()
-                    .expect("Unable to find the Window")
-                    .set_position(((resolution.0 - width) / 2) as i32, ((resolution.1 - height) / 2) as i32);
-                facade
-            },
-            WindowArgs::Borderless(title) => {
-                let facade = WindowBuilder::new()
-                    .with_title(title)
-                    .with_dimensions(resolution.0, resolution.1)
-                    .with_decorations(false)
-                    .with_depth_buffer(24)
-                    .with_vsync()
-                    .build_glium()
-                    .expect("Unable to make Facade");
-                facade.get_window()
-                    .expect("Unable to find Window")
-                    .set_position(0, 0);
-                facade
-            },
-        };
+    pub fn from_backend(backend: Box<Backend>) -> Window {
         Window {
-            program: Program::from_source(&facade, vertex_shader_src, fragment_shader_src, None).expect("Unable to make Shader Program"),
-            facade: facade,
-            texture_buffers: HashMap::new(),
-            vertex_buffers: HashMap::new(),
-            index_buffers: HashMap::new(),
-            draw_parameters: HashMap::new(),
-            resolution: resolution,
+            backend: backend,
+            assets: AssetManager::new(),
         }
     }
 
     pub fn get_resolution_vec2(&self) -> Vec2 {
-        Vec2::from([self.resolution.0 as f32, self.resolution.1 as f32])
+        self.backend.get_resolution()
     }
 
     pub fn frame(&mut self) -> Frame {
-        Frame::new(&mut self.facade, &mut self.program, &mut self.texture_buffers, &mut self.vertex_buffers, &mut self.index_buffers, &mut self.draw_parameters)
+        Frame::new(&mut *self.backend)
     }
 
-    pub fn poll_events(&self) -> PollEventsIter {
-        self.facade.poll_events()
+    pub fn poll_events(&mut self) -> Vec<WindowEvent> {
+        self.backend.poll_events()
+    }
+
+    pub fn set_cursor_state(&mut self, state: CursorState) -> Result<(), String> {
+        self.backend.set_cursor_state(state)
     }
 
     pub fn set_vertices(&mut self, entity: &Arc<RwLock<Entity>>, vertices: Vec<Vertex>) {
-        self.vertex_buffers.insert(entity.read().expect("Unable to Read Entity in Set Vertices").vertex_id, VertexBuffer::new(&self.facade, &vertices).expect("Failed to Create Vertex Buffer"));
+        self.backend.set_vertices(entity.read().expect("Unable to Read Entity in Set Vertices").vertex_id, vertices);
     }
 
     pub fn set_indices(&mut self, entity: &Arc<RwLock<Entity>>, indices: Vec<Index>) {
-        self.index_buffers.insert(entity.read().expect("Unable to Read Entity in Set Indices").index_id, IndexBuffer::new(&self.facade, glium::index::PrimitiveType::TrianglesList, &indices).expect("Failed to Create Index Buffer"));
+        self.backend.set_indices(entity.read().expect("Unable to Read Entity in Set Indices").index_id, indices);
     }
 
     pub fn set_texture(&mut self, entity: &Arc<RwLock<Entity>>, data: &[u8]) {
         let texture = load_from_memory(data).expect("Error Loading Image").to_rgba();
-        self.texture_buffers.insert(entity.read().expect("Unable to Read Entity in Set Texture").texture_id, Texture2d::new(&self.facade, RawImage2d::from_raw_rgba_reversed(texture.clone().into_raw(), texture.dimensions())).expect("Unable to make Texture"));
+        self.backend.set_texture(entity.read().expect("Unable to Read Entity in Set Texture").texture_id, texture);
     }
 
     pub fn set_draw_parameters(&mut self, entity: &Arc<RwLock<Entity>>, draw_parameters: DrawParameters<'static>) {
-        self.draw_parameters.insert(entity.read().expect("Unable to Read Entity in Set Draw Parameters").draw_parameters_id, draw_parameters);
+        self.backend.set_draw_parameters(entity.read().expect("Unable to Read Entity in Set Draw Parameters").draw_parameters_id, draw_parameters);
+    }
+
+    pub fn upload_atlas(&mut self, manager: Arc<RwLock<IDManager>>, builder: AtlasBuilder) -> Arc<Atlas> {
+        let (image, regions) = builder.build();
+        let id = ID::new(manager.clone(), IDType::Texture);
+        self.backend.set_texture(id, image);
+        Arc::new(Atlas::new(manager, id, regions))
+    }
+
+    pub fn load_texture(&mut self, entity_arc: &Arc<RwLock<Entity>>, path: &Path) -> Result<ID, AssetError> {
+        let mut entity = entity_arc.write().expect("Unable to Write Entity in Load Texture");
+        let id = self.assets.load_texture(&mut *self.backend, entity.manager.clone(), path)?;
+        entity.use_id(id, IDType::Texture);
+        self.assets.add_user(path, entity_arc);
+        Ok(id)
+    }
+
+    pub fn unload_texture(&mut self, path: &Path) {
+        self.assets.unload_texture(path);
+    }
+
+    pub fn set_hot_reload(&mut self, watching: bool) {
+        self.assets.set_watching(watching);
+    }
+
+    pub fn reload_assets(&mut self) -> Vec<AssetError> {
+        self.assets.reload_changed(&mut *self.backend)
+    }
+
+    pub fn release(&mut self, id_type: IDType, id: ID) {
+        self.backend.release(id_type, id);
     }
 }
 
@@ -209,63 +174,49 @@ pub enum WindowArgs {
 }
 
 pub struct Frame<'a> {
-    program: &'a mut Program,
-    texture_buffers: &'a mut HashMap<ID, Texture2d>,
-    vertex_buffers: &'a mut HashMap<ID, VertexBuffer<Vertex>>,
-    index_buffers: &'a mut HashMap<ID, IndexBuffer<Index>>,
-    draw_parameters: &'a mut HashMap<ID, DrawParameters<'static>>,
-    frame: glium::Frame,
+    backend: &'a mut Backend,
+    draw_calls: Vec<DrawCall>,
 }
 
 impl<'a> Frame<'a> {
-    fn new(
-        facade: &'a mut GlutinFacade,
-        program: &'a mut Program,
-        texture_buffers: &'a mut HashMap<ID, Texture2d>,
-        vertex_buffers: &'a mut HashMap<ID, VertexBuffer<Vertex>>,
-        index_buffers: &'a mut HashMap<ID, IndexBuffer<Index>>,
-        draw_parameters: &'a mut HashMap<ID, DrawParameters<'static>>,
-    ) -> Frame<'a> {
-        let mut frame = facade.draw();
-        frame.clear_color_and_depth((0.0, 0.0, 0.0, 1.0), 1.0);
+    fn new(backend: &'a mut Backend) -> Frame<'a> {
+        backend.begin_frame();
         Frame {
-            frame: frame,
-            program: program,
-            texture_buffers: texture_buffers,
-            vertex_buffers: vertex_buffers,
-            index_buffers: index_buffers,
-            draw_parameters: draw_parameters,
+            backend: backend,
+            draw_calls: vec!(),
         }
     }
 
     pub fn draw_entity(&mut self, entity_arc: &Arc<RwLock<Entity>>, transforms: &Arc<RwLock<Transforms>>) {
         let entity = entity_arc.read().expect("Unable to Read Entity in Draw Entity");
-        self.frame.draw(
-            self.vertex_buffers.get(&entity.vertex_id).expect("Unable to Get Vertex Buffer in Draw Entity"),
-            self.index_buffers.get(&entity.index_id).expect("Unable to Get Index Buffer in Draw Entity"),
-            &self.program,
-            &uniform!(
-                tex: self.texture_buffers.get(&entity.texture_id).expect("Unable to Get Texture Buffer in Draw Entity"),
-                perspective: transforms.read().expect("Unable to Read Transforms in Draw Entity in Frame").get_perspective_matrix(&entity),
-                view: transforms.read().expect("Unable to Read Transforms in Draw Entity In Frame").get_view_matrix(&entity),
-                model: transforms.read().expect("Unable to Read Transforms in Draw Entity in Frame").get_model_matrix(&entity),
-            ),
-            self.draw_parameters.get(&entity.draw_parameters_id).expect("Unable to Get Draw Parameter in Draw Entity"))
-            .expect("Unable to draw Entity");
-    }
-
-    pub fn end(self) {
-        self.frame.finish().expect("Unable to Finish Frame");
+        self.draw(&entity, &transforms.read().expect("Unable to Read Transforms in Draw Entity in Frame"));
+    }
+
+    pub fn draw(&mut self, entity: &Entity, transforms: &Transforms) {
+        let key = BatchKey::new(entity);
+        let merge = self.draw_calls.last().map_or(false, |draw_call| draw_call.get_key() == key);
+        if !merge {
+            self.draw_calls.push(DrawCall::new(key, transforms.get_perspective_matrix(entity), transforms.get_view_matrix(entity)));
+        }
+        self.draw_calls.last_mut().expect("Unable to Get Draw Call in Draw in Frame").push_model(transforms.get_model_matrix(entity));
+    }
+
+    pub fn end(mut self) {
+        let length = self.draw_calls.len();
+        for draw_call in self.draw_calls.drain(0..length) {
+            self.backend.draw(draw_call);
+        }
+        self.backend.end_frame();
     }
 }
 
 pub struct Transforms {
     perspective_mat4s: Arc<RwLock<HashMap<ID, Mat4>>>,
-    perspective_mat4s_inverse: Arc<RwLock<HashMap<ID, Mat4>>>,
+    perspective_mat4s_inverse: Arc<RwLock<HashMap<ID, Option<Mat4>>>>,
     view_mat4s: Arc<RwLock<HashMap<ID, Mat4>>>,
-    view_mat4s_inverse: Arc<RwLock<HashMap<ID, Mat4>>>,
+    view_mat4s_inverse: Arc<RwLock<HashMap<ID, Option<Mat4>>>>,
     model_mat4s: Arc<RwLock<HashMap<ID, Mat4>>>,
-    model_mat4s_inverse: Arc<RwLock<HashMap<ID, Mat4>>>,
+    model_mat4s_inverse: Arc<RwLock<HashMap<ID, Option<Mat4>>>>,
 }
 
 impl Transforms {
@@ -280,27 +231,57 @@ impl Transforms {
         }
     }
 
-    pub fn backwards2(&self, vec2: Vec2, entity: &Entity) -> Vec2 {
-        Vec2::from(self.get_perspective_inverse(entity) * self.get_view_inverse(entity) * self.get_model_inverse(entity) * vec2.to_vec4(0.0, 0.0))
+    pub fn release(&self, id_type: IDType, id: ID) {
+        match id_type {
+            IDType::Perspective => {
+                self.perspective_mat4s.write().expect("Unable to Write Perspective Matrix in Release in Transforms").remove(&id);
+                self.perspective_mat4s_inverse.write().expect("Unable to Write Perspective Inverse in Release in Transforms").remove(&id);
+            },
+            IDType::View => {
+                self.view_mat4s.write().expect("Unable to Write View Matrix in Release in Transforms").remove(&id);
+                self.view_mat4s_inverse.write().expect("Unable to Write View Inverse in Release in Transforms").remove(&id);
+            },
+            IDType::Model => {
+                self.model_mat4s.write().expect("Unable to Write Model Matrix in Release in Transforms").remove(&id);
+                self.model_mat4s_inverse.write().expect("Unable to Write Model Inverse in Release in Transforms").remove(&id);
+            },
+            _ => (),
+        }
+    }
+
+    fn invert(matrix: Mat4) -> Option<Mat4> {
+        matrix.try_affine_inverse().or_else(|| matrix.try_inverse())
+    }
+
+    fn get_inverse(&self, entity: &Entity) -> Option<Mat4> {
+        match (self.get_model_inverse(entity), self.get_view_inverse(entity), self.get_perspective_inverse(entity)) {
+            (Some(model), Some(view), Some(perspective)) => Some(model * view * perspective),
+            _ => None,
+        }
+    }
+
+    pub fn backwards2(&self, vec2: Vec2, entity: &Entity) -> Option<Vec2> {
+        self.get_inverse(entity).map(|inverse| Vec2::from(inverse * vec2.to_vec4(0.0, 0.0)))
     }
 
-    pub fn backwards3(&self, vec3: Vec3, entity: &Entity) -> Vec3 {
-        Vec3::from(self.get_perspective_inverse(entity) * self.get_view_inverse(entity) * self.get_model_inverse(entity) * vec3.to_vec4(0.0))
+    pub fn backwards3(&self, vec3: Vec3, entity: &Entity) -> Option<Vec3> {
+        self.get_inverse(entity).map(|inverse| Vec3::from(inverse * vec3.to_vec4(0.0)))
     }
 
-    pub fn backwards4(&self, vec4: Vec4, entity: &Entity) -> Vec4 {
-        self.get_perspective_inverse(entity) * self.get_view_inverse(entity) * self.get_model_inverse(entity) * vec4
+    pub fn backwards4(&self, vec4: Vec4, entity: &Entity) -> Option<Vec4> {
+        self.get_inverse(entity).map(|inverse| inverse * vec4)
     }
 
     pub fn get_perspective_matrix(&self, entity: &Entity) -> Mat4 {
         *self.perspective_mat4s.read().expect("Unable to Read Perspective Matrix in Transforms").get(&entity.perspective_id).expect("Unable to Get Perspective in Get Perspective")
     }
 
-    pub fn get_perspective_inverse(&self, entity: &Entity) -> Mat4 {
+    pub fn get_perspective_inverse(&self, entity: &Entity) -> Option<Mat4> {
         *self.perspective_mat4s_inverse.read().expect("Unable to Read Perspective Inverse in Transforms").get(&entity.perspective_id).expect("Unable to Get Perspective Inverse in Get Perspective Inverse")
     }
 
-    pub fn set_perspective_matrix(&self, entity: &Arc<RwLock<Entity>>, perspective: Mat4, inverse: Mat4) {
+    pub fn set_perspective_matrix(&self, entity: &Arc<RwLock<Entity>>, perspective: Mat4) {
+        let inverse = Transforms::invert(perspective);
         self.perspective_mat4s.write().expect("Unable to Write Perspective Matrix in Set Perspective Matrix in Transforms").insert(entity.read().expect("Unable to Read Entity in Set Perspective Matrix").perspective_id, perspective);
         self.perspective_mat4s_inverse.write().expect("Unable to Write Perspective Inverse in Set Perspective Matrix in Transforms").insert(entity.read().expect("Unable to Read Entity in Set Perspective Matrix").perspective_id, inverse);
     }
@@ -309,11 +290,12 @@ impl Transforms {
         *self.view_mat4s.read().expect("Unable to Read View Matrix in Get View Matrix in Transforms").get(&entity.view_id).expect("Unable to Get View in Get View")
     }
 
-    pub fn get_view_inverse(&self, entity: &Entity) -> Mat4 {
+    pub fn get_view_inverse(&self, entity: &Entity) -> Option<Mat4> {
         *self.view_mat4s_inverse.read().expect("Unable to Read View Inverse in Get View Inverse in Transforms").get(&entity.view_id).expect("Unable to Get View Inverse in Get View Inverse")
     }
 
-    pub fn set_view_matrix(&self, entity: &Arc<RwLock<Entity>>, view: Mat4, inverse: Mat4) {
+    pub fn set_view_matrix(&self, entity: &Arc<RwLock<Entity>>, view: Mat4) {
+        let inverse = Transforms::invert(view);
         self.view_mat4s.write().expect("Unable to Write View Matrix in Set View Matrix in Transforms").insert(entity.read().expect("Unable to Read Entity in Set View Matrix").view_id, view);
         self.view_mat4s_inverse.write().expect("Unable to Write View Inverse in Set View Matrix in Transforms").insert(entity.read().expect("Unable to Read Entity in Set View Matrix").view_id, inverse);
     }
@@ -322,17 +304,19 @@ impl Transforms {
         *self.model_mat4s.read().expect("Unable to Read Model Matrix in Get Model Matrix in Transforms").get(&entity.model_id).expect("Unable to Get Model in Get Model")
     }
 
-    pub fn get_model_inverse(&self, entity: &Entity) -> Mat4 {
+    pub fn get_model_inverse(&self, entity: &Entity) -> Option<Mat4> {
         *self.model_mat4s_inverse.read().expect("Unable to Read Model Inverse in Get Model Inverse in Transforms").get(&entity.model_id).expect("Unable to Get Model Inverse in Get Model Inverse")
     }
 
-    pub fn set_model_matrix(&self, entity: &Arc<RwLock<Entity>>, model: Mat4, inverse: Mat4) {
+    pub fn set_model_matrix(&self, entity: &Arc<RwLock<Entity>>, model: Mat4) {
+        let inverse = Transforms::invert(model);
         self.model_mat4s.write().expect("Unable to Write Model Matrix in Set Model Matrix in Transforms").insert(entity.read().expect("Unable to Read Entity in Set Model Matrix").model_id, model);
         self.model_mat4s_inverse.write().expect("Unable to Write Model Inverse in Set Model Matrix in Transforms").insert(entity.read().expect("Unable to Read Entity in Set Model Matrix").model_id, inverse);
     }
 }
 
 pub struct Entity {
+    manager: Arc<RwLock<IDManager>>,
     texture_id: ID,
     vertex_id: ID,
     index_id: ID,
@@ -352,12 +336,48 @@ impl Entity {
             perspective_id: ID::new(manager.clone(), IDType::Perspective),
             view_id: ID::new(manager.clone(), IDType::View),
             model_id: ID::new(manager.clone(), IDType::Model),
+            manager: manager,
         }
     }
 
+    pub fn get_texture_id(&self) -> ID {
+        self.texture_id
+    }
+
+    pub fn get_vertex_id(&self) -> ID {
+        self.vertex_id
+    }
+
+    pub fn get_index_id(&self) -> ID {
+        self.index_id
+    }
+
+    pub fn get_draw_parameters_id(&self) -> ID {
+        self.draw_parameters_id
+    }
+
+    pub fn get_perspective_id(&self) -> ID {
+        self.perspective_id
+    }
+
+    pub fn get_view_id(&self) -> ID {
+        self.view_id
+    }
+
+    pub fn get_model_id(&self) -> ID {
+        self.model_id
+    }
+
     pub fn new_from(entity: &Arc<RwLock<Entity>>) -> Entity {
         let entity = entity.read().expect("Unable to Read Entity in New From in Entity");
+        {
+            let mut manager = entity.manager.write().expect("Unable to Write Manager in New From in Entity");
+            for id_type in ID_TYPES.iter() {
+                manager.retain(*id_type, entity.get_id(*id_type));
+            }
+        }
         Entity {
+            manager: entity.manager.clone(),
             texture_id: entity.texture_id,
             vertex_id: entity.vertex_id,
             index_id: entity.index_id,
@@ -369,34 +389,35 @@ impl Entity {
     }
 
     pub fn use_old_id(&mut self, other_arc: &Arc<RwLock<Entity>>, id_type: IDType) {
-        let other = other_arc.read().expect("Unable to Read Other in Use Other ID");
-        match id_type {
-            IDType::Vertex => {
-                self.vertex_id = other.vertex_id;
-            },
-            IDType::Index => {
-                self.index_id = other.index_id;
-            },
-            IDType::Texture => {
-                self.texture_id = other.texture_id;
-            },
-            IDType::DrawParameter => {
-                self.draw_parameters_id = other.draw_parameters_id;
-            },
-            IDType::Perspective => {
-                self.perspective_id = other.perspective_id;
-            },
-            IDType::View => {
-                self.view_id = other.view_id;
-            },
-            IDType::Model => {
-                self.model_id = other.model_id;
-            },
-        };
+        let id = other_arc.read().expect("Unable to Read Other in Use Other ID").get_id(id_type);
+        self.manager.write().expect("Unable to Write Manager in Use Old ID in Entity").retain(id_type, id);
+        self.replace_id(id_type, id);
+    }
+
+    pub fn use_id(&mut self, id: ID, id_type: IDType) {
+        self.manager.write().expect("Unable to Write Manager in Use ID in Entity").retain(id_type, id);
+        self.replace_id(id_type, id);
     }
 
     pub fn use_new_id(&mut self, manager: Arc<RwLock<IDManager>>, id_type: IDType) {
         let id = ID::new(manager, id_type);
+        self.replace_id(id_type, id);
+    }
+
+    fn get_id(&self, id_type: IDType) -> ID {
+        match id_type {
+            IDType::Vertex => self.vertex_id,
+            IDType::Index => self.index_id,
+            IDType::Texture => self.texture_id,
+            IDType::DrawParameter => self.draw_parameters_id,
+            IDType::Perspective => self.perspective_id,
+            IDType::View => self.view_id,
+            IDType::Model => self.model_id,
+        }
+    }
+
+    fn replace_id(&mut self, id_type: IDType, id: ID) {
+        let old = self.get_id(id_type);
         match id_type {
             IDType::Vertex => {
                 self.vertex_id = id;
@@ -420,9 +441,21 @@ impl Entity {
                 self.model_id = id;
             },
         }
+        self.manager.write().expect("Unable to Write Manager in Replace ID in Entity").release(id_type, old);
     }
 }
 
+impl Drop for Entity {
+    fn drop(&mut self) {
+        let mut manager = self.manager.write().expect("Unable to Write Manager in Drop in Entity");
+        for id_type in ID_TYPES.iter() {
+            manager.release(*id_type, self.get_id(*id_type));
+        }
+    }
+}
+
+const ID_TYPES: [IDType; 7] = [IDType::Vertex, IDType::Index, IDType::Texture, IDType::DrawParameter, IDType::Perspective, IDType::View, IDType::Model];
+
 #[derive(Copy, Clone)]
 pub struct Vertex {
     position: [f32; 3],
@@ -436,6 +469,14 @@ impl Vertex {
             tex_coord: tex_coord,
         }
     }
+
+    pub fn get_position(&self) -> [f32; 3] {
+        self.position
+    }
+
+    pub fn get_tex_coord(&self) -> [f32; 2] {
+        self.tex_coord
+    }
 }
 
 impl From<Vec2> for Vertex {
@@ -453,3 +494,30 @@ impl From<Vec3> for Vertex {
 pub fn init_vertex() {
     implement_vertex!(Vertex, position, tex_coord);
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, RwLock};
+
+    use math::{Mat4, Vec3, Vec4};
+    use graphics::{IDManager, Transforms, Entity};
+
+    #[test]
+    fn singular_matrices_store_no_inverse() {
+        let manager = Arc::new(RwLock::new(IDManager::new()));
+        let entity = Arc::new(RwLock::new(Entity::new(manager)));
+        let transforms = Transforms::new();
+        transforms.set_perspective_matrix(&entity, Mat4::identity());
+        transforms.set_view_matrix(&entity, Mat4::translation_from_vec3(Vec3::from([0.0, 0.0, -5.0])));
+        transforms.set_model_matrix(&entity, Mat4::scalation_from_vec3(Vec3::from([2.0, 2.0, 2.0])));
+        let point = Vec4::from([1.0, 1.0, 1.0, 1.0]);
+        let expected = Vec4::from([0.5, 0.5, 3.0, 1.0]);
+        assert!(transforms.backwards4(point, &entity.read().expect("Unable to Read Entity in Test")).expect("Unable to Go Backwards in Test").approx_eq(expected, 1e-6));
+
+        transforms.set_model_matrix(&entity, Mat4::scalation_from_vec3(Vec3::from([2.0, 0.0, 2.0])));
+        let entity = entity.read().expect("Unable to Read Entity in Test");
+        assert!(transforms.get_model_inverse(&entity).is_none());
+        assert!(transforms.get_view_inverse(&entity).is_some());
+        assert!(transforms.backwards4(point, &entity).is_none());
+    }
+}
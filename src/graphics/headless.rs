@@ -0,0 +1,191 @@
+use glium::glutin::Event as WindowEvent;
+use glium::{DrawParameters};
+use image::{RgbaImage};
+use std::collections::{HashMap};
+use std::sync::{Arc, RwLock};
+
+use math::{Vec2};
+use utils::{CursorState};
+use graphics::{Backend, DrawCall, Vertex, Index, ID, IDType};
+
+#[derive(Clone)]
+pub enum Upload {
+    Vertices(ID, Vec<Vertex>),
+    Indices(ID, Vec<Index>),
+    Texture(ID, (u32, u32)),
+    DrawParameters(ID),
+    Release(IDType, ID),
+}
+
+pub struct HeadlessLog {
+    uploads: Vec<Upload>,
+    frames: Vec<Vec<DrawCall>>,
+    events: Vec<WindowEvent>,
+    cursor_state: CursorState,
+}
+
+impl HeadlessLog {
+    pub fn new() -> HeadlessLog {
+        HeadlessLog {
+            uploads: vec!(),
+            frames: vec!(),
+            events: vec!(),
+            cursor_state: CursorState::Normal,
+        }
+    }
+
+    pub fn get_uploads(&self) -> &Vec<Upload> {
+        &self.uploads
+    }
+
+    pub fn get_frames(&self) -> &Vec<Vec<DrawCall>> {
+        &self.frames
+    }
+
+    pub fn get_frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn get_last_frame(&self) -> Option<&Vec<DrawCall>> {
+        self.frames.last()
+    }
+
+    pub fn get_cursor_state(&self) -> CursorState {
+        self.cursor_state
+    }
+
+    pub fn push_event(&mut self, event: WindowEvent) {
+        self.events.push(event);
+    }
+
+    pub fn clear(&mut self) {
+        self.uploads.clear();
+        self.frames.clear();
+        self.events.clear();
+    }
+}
+
+pub struct HeadlessBackend {
+    log: Arc<RwLock<HeadlessLog>>,
+    vertex_buffers: HashMap<ID, usize>,
+    index_buffers: HashMap<ID, usize>,
+    texture_buffers: HashMap<ID, (u32, u32)>,
+    draw_parameters: HashMap<ID, DrawParameters<'static>>,
+    frame: Option<Vec<DrawCall>>,
+    resolution: Vec2,
+}
+
+impl HeadlessBackend {
+    pub fn new(width: u32, height: u32) -> HeadlessBackend {
+        HeadlessBackend {
+            log: Arc::new(RwLock::new(HeadlessLog::new())),
+            vertex_buffers: HashMap::new(),
+            index_buffers: HashMap::new(),
+            texture_buffers: HashMap::new(),
+            draw_parameters: HashMap::new(),
+            frame: None,
+            resolution: Vec2::from([width as f32, height as f32]),
+        }
+    }
+
+    pub fn get_log(&self) -> Arc<RwLock<HeadlessLog>> {
+        self.log.clone()
+    }
+
+    fn record(&mut self, upload: Upload) {
+        self.log.write().expect("Unable to Write Log in Record in Headless Backend").uploads.push(upload);
+    }
+}
+
+impl Backend for HeadlessBackend {
+    fn get_resolution(&self) -> Vec2 {
+        self.resolution
+    }
+
+    fn poll_events(&mut self) -> Vec<WindowEvent> {
+        let mut log = self.log.write().expect("Unable to Write Log in Poll Events in Headless Backend");
+        let length = log.events.len();
+        log.events.drain(0..length).collect()
+    }
+
+    fn set_cursor_state(&mut self, state: CursorState) -> Result<(), String> {
+        self.log.write().expect("Unable to Write Log in Set Cursor State in Headless Backend").cursor_state = state;
+        Ok(())
+    }
+
+    fn set_vertices(&mut self, id: ID, vertices: Vec<Vertex>) {
+        self.vertex_buffers.insert(id, vertices.len());
+        self.record(Upload::Vertices(id, vertices));
+    }
+
+    fn set_indices(&mut self, id: ID, indices: Vec<Index>) {
+        self.index_buffers.insert(id, indices.len());
+        self.record(Upload::Indices(id, indices));
+    }
+
+    fn set_texture(&mut self, id: ID, texture: RgbaImage) {
+        let dimensions = texture.dimensions();
+        self.texture_buffers.insert(id, dimensions);
+        self.record(Upload::Texture(id, dimensions));
+    }
+
+    fn set_draw_parameters(&mut self, id: ID, draw_parameters: DrawParameters<'static>) {
+        self.draw_parameters.insert(id, draw_parameters);
+        self.record(Upload::DrawParameters(id));
+    }
+
+    fn release(&mut self, id_type: IDType, id: ID) {
+        let known = match id_type {
+            IDType::Vertex => self.vertex_buffers.remove(&id).is_some(),
+            IDType::Index => self.index_buffers.remove(&id).is_some(),
+            IDType::Texture => self.texture_buffers.remove(&id).is_some(),
+            IDType::DrawParameter => self.draw_parameters.remove(&id).is_some(),
+            _ => false,
+        };
+        if known {
+            self.record(Upload::Release(id_type, id));
+        }
+    }
+
+    fn begin_frame(&mut self) {
+        self.frame = Some(vec!());
+    }
+
+    fn draw(&mut self, draw_call: DrawCall) {
+        self.vertex_buffers.get(&draw_call.get_vertex_id()).expect("Unable to Get Vertex Buffer in Draw");
+        self.index_buffers.get(&draw_call.get_index_id()).expect("Unable to Get Index Buffer in Draw");
+        self.texture_buffers.get(&draw_call.get_texture_id()).expect("Unable to Get Texture Buffer in Draw");
+        self.draw_parameters.get(&draw_call.get_draw_parameters_id()).expect("Unable to Get Draw Parameter in Draw");
+        self.frame.as_mut().expect("Unable to Get Frame in Draw in Headless Backend").push(draw_call);
+    }
+
+    fn end_frame(&mut self) {
+        let frame = self.frame.take().expect("Unable to Take Frame in End Frame in Headless Backend");
+        self.log.write().expect("Unable to Write Log in End Frame in Headless Backend").frames.push(frame);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glium::glutin::Event as WindowEvent;
+
+    use graphics::{Backend, HeadlessBackend};
+
+    #[test]
+    fn clear_drops_pending_events() {
+        let mut backend = HeadlessBackend::new(4, 4);
+        let log = backend.get_log();
+        log.write().expect("Unable to Write Log in Test").push_event(WindowEvent::Focused(true));
+        log.write().expect("Unable to Write Log in Test").clear();
+        assert!(backend.poll_events().is_empty());
+    }
+
+    #[test]
+    fn poll_events_drains_pushed_events() {
+        let mut backend = HeadlessBackend::new(4, 4);
+        let log = backend.get_log();
+        log.write().expect("Unable to Write Log in Test").push_event(WindowEvent::Focused(true));
+        assert_eq!(backend.poll_events().len(), 1);
+        assert!(backend.poll_events().is_empty());
+    }
+}
@@ -2,26 +2,29 @@ use std::collections::{HashMap};
 use std::fmt::{Display, Formatter, Error};
 use std::sync::{Arc, RwLock};
 
-#[derive(Copy, Clone, Eq, Hash, PartialEq)]
+#[derive(Copy, Clone, Eq, Hash, PartialEq, Ord, PartialOrd)]
 pub struct ID {
     id: IDSize,
+    generation: IDSize,
 }
 
 impl ID {
     pub fn new(manager: Arc<RwLock<IDManager>>, id_type: IDType) -> ID {
-        ID {
-            id: manager.write().expect("Unable to Write Manager in New ID").get_id(id_type),
-        }
+        manager.write().expect("Unable to Write Manager in New ID").get_id(id_type)
     }
 
     pub fn get_id(&self) -> IDSize {
         self.id
     }
+
+    pub fn get_generation(&self) -> IDSize {
+        self.generation
+    }
 }
 
 impl Display for ID {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error>{
-        write!(f, "{}", self.id)
+        write!(f, "{}:{}", self.id, self.generation)
     }
 }
 
@@ -36,25 +39,150 @@ pub enum IDType {
     Model,
 }
 
+struct IDPool {
+    next: IDSize,
+    free: Vec<IDSize>,
+    generations: HashMap<IDSize, IDSize>,
+    counts: HashMap<IDSize, u32>,
+}
+
+impl IDPool {
+    fn new() -> IDPool {
+        IDPool {
+            next: 1,
+            free: vec!(),
+            generations: HashMap::new(),
+            counts: HashMap::new(),
+        }
+    }
+
+    fn get_generation(&self, id: IDSize) -> IDSize {
+        match self.generations.get(&id) {
+            Some(generation) => *generation,
+            None => 0,
+        }
+    }
+
+    fn is_live(&self, id: ID) -> bool {
+        self.counts.contains_key(&id.id) && self.get_generation(id.id) == id.generation
+    }
+}
+
 pub struct IDManager {
-    map: HashMap<IDType, IDSize>,
+    pools: HashMap<IDType, IDPool>,
+    released: Vec<(IDType, ID)>,
 }
 
 impl IDManager {
     pub fn new() -> IDManager {
         IDManager {
-            map: HashMap::new(),
+            pools: HashMap::new(),
+            released: vec!(),
+        }
+    }
+
+    fn get_id(&mut self, id_type: IDType) -> ID {
+        let pool = self.pools.entry(id_type).or_insert_with(IDPool::new);
+        let id = match pool.free.pop() {
+            Some(id) => id,
+            None => {
+                let id = pool.next;
+                pool.next += 1;
+                id
+            },
+        };
+        pool.counts.insert(id, 1);
+        ID {
+            id: id,
+            generation: pool.get_generation(id),
+        }
+    }
+
+    pub fn retain(&mut self, id_type: IDType, id: ID) {
+        let pool = match self.pools.get_mut(&id_type) {
+            Some(pool) => pool,
+            None => return,
+        };
+        if pool.is_live(id) {
+            *pool.counts.get_mut(&id.id).expect("Unable to Get Count in Retain in ID Manager") += 1;
         }
     }
 
-    fn get_id(&mut self, id_type: IDType) -> IDSize {
-        let id = match self.map.get(&id_type) {
-            Some(id) => *id,
-            None => 1,
+    pub fn release(&mut self, id_type: IDType, id: ID) {
+        let pool = match self.pools.get_mut(&id_type) {
+            Some(pool) => pool,
+            None => return,
         };
-        self.map.insert(id_type, id + 1);
-        id
+        if !pool.is_live(id) {
+            return;
+        }
+        let count = {
+            let count = pool.counts.get_mut(&id.id).expect("Unable to Get Count in Release in ID Manager");
+            *count -= 1;
+            *count
+        };
+        if count == 0 {
+            pool.counts.remove(&id.id);
+            pool.generations.insert(id.id, id.generation + 1);
+            pool.free.push(id.id);
+            self.released.push((id_type, id));
+        }
+    }
+
+    pub fn get_ref_count(&self, id_type: IDType, id: ID) -> u32 {
+        match self.pools.get(&id_type) {
+            Some(pool) => {
+                if pool.is_live(id) {
+                    *pool.counts.get(&id.id).expect("Unable to Get Count in Get Ref Count in ID Manager")
+                } else {
+                    0
+                }
+            },
+            None => 0,
+        }
+    }
+
+    pub fn take_released(&mut self) -> Vec<(IDType, ID)> {
+        let length = self.released.len();
+        self.released.drain(0..length).collect()
     }
 }
 
 pub type IDSize = u32;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, RwLock};
+
+    use graphics::{ID, IDType, IDManager};
+
+    #[test]
+    fn ids_are_reference_counted_and_recycled_with_new_generations() {
+        let manager = Arc::new(RwLock::new(IDManager::new()));
+        let id = ID::new(manager.clone(), IDType::Vertex);
+        let mut manager = manager.write().expect("Unable to Write Manager in Test");
+        manager.retain(IDType::Vertex, id);
+        assert_eq!(manager.get_ref_count(IDType::Vertex, id), 2);
+        manager.release(IDType::Vertex, id);
+        assert!(manager.take_released().is_empty());
+        manager.release(IDType::Vertex, id);
+        assert_eq!(manager.get_ref_count(IDType::Vertex, id), 0);
+        assert_eq!(manager.take_released().len(), 1);
+        let recycled = manager.get_id(IDType::Vertex);
+        assert_eq!(recycled.get_id(), id.get_id());
+        assert!(recycled.get_generation() != id.get_generation());
+        manager.release(IDType::Vertex, id);
+        assert_eq!(manager.get_ref_count(IDType::Vertex, recycled), 1);
+    }
+
+    #[test]
+    fn unknown_pools_are_ignored() {
+        let manager = Arc::new(RwLock::new(IDManager::new()));
+        let id = ID::new(manager.clone(), IDType::Vertex);
+        let mut manager = manager.write().expect("Unable to Write Manager in Test");
+        manager.retain(IDType::Texture, id);
+        manager.release(IDType::Texture, id);
+        assert_eq!(manager.get_ref_count(IDType::Texture, id), 0);
+        assert!(manager.take_released().is_empty());
+    }
+}
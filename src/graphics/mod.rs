@@ -1,5 +1,17 @@
 mod graphics;
 mod ids;
+mod backend;
+mod glium_backend;
+mod headless;
+mod assets;
+mod atlas;
+mod camera;
 
-pub use self::graphics::{Index, DrawMethod, Window, Frame, WindowArgs, Transforms, Entity, Vertex, init_vertex, method_to_parameters};
+pub use self::graphics::{Index, DrawMethod, DepthTestMethod, CullingMethod, Window, Frame, WindowArgs, Transforms, Entity, Vertex, init_vertex, method_to_parameters};
 pub use self::ids::{ID, IDType, IDManager};
+pub use self::backend::{Backend, BatchKey, DrawCall};
+pub use self::glium_backend::{GliumBackend};
+pub use self::headless::{HeadlessBackend, HeadlessLog, Upload};
+pub use self::assets::{AssetManager, AssetError, read_file};
+pub use self::atlas::{AtlasBuilder, AtlasError, Atlas, UvRect};
+pub use self::camera::{Camera, Projection};
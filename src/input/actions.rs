@@ -0,0 +1,358 @@
+use std::collections::{HashMap};
+use std::error::{Error as StdError};
+use std::fmt::{Display, Formatter, Error};
+use std::fs::{File};
+use std::io::{Read, Write};
+use std::io;
+use std::path::{Path};
+
+use input::{Button};
+use utils::{KeyCode, MouseButton, key_code_name, key_code_from_name, mouse_button_name, mouse_button_from_name};
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum InputSource {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Binding {
+    source: InputSource,
+    modifiers: Vec<KeyCode>,
+}
+
+impl Binding {
+    pub fn key(key_code: KeyCode) -> Binding {
+        Binding {
+            source: InputSource::Key(key_code),
+            modifiers: vec!(),
+        }
+    }
+
+    pub fn mouse(mouse_button: MouseButton) -> Binding {
+        Binding {
+            source: InputSource::Mouse(mouse_button),
+            modifiers: vec!(),
+        }
+    }
+
+    pub fn with_modifier(mut self, modifier: KeyCode) -> Binding {
+        self.modifiers.push(modifier);
+        self
+    }
+
+    pub fn get_source(&self) -> InputSource {
+        self.source
+    }
+
+    pub fn get_modifiers(&self) -> &Vec<KeyCode> {
+        &self.modifiers
+    }
+
+    fn modifiers_held(&self, lookup: &Fn(InputSource) -> Button) -> bool {
+        self.modifiers.iter().all(|modifier| lookup(InputSource::Key(*modifier)).is_pressed())
+    }
+
+    pub fn is_pressed(&self, lookup: &Fn(InputSource) -> Button) -> bool {
+        self.modifiers_held(lookup) && lookup(self.source).is_pressed()
+    }
+
+    pub fn just_pressed(&self, lookup: &Fn(InputSource) -> Button, tick_number: u64) -> bool {
+        self.modifiers_held(lookup) && lookup(self.source).just_pressed(tick_number)
+    }
+
+    pub fn just_released(&self, lookup: &Fn(InputSource) -> Button, tick_number: u64) -> bool {
+        self.modifiers_held(lookup) && lookup(self.source).just_released(tick_number)
+    }
+
+    fn parse(text: &str) -> Result<Binding, String> {
+        let mut parts: Vec<&str> = text.split('+').map(|part| part.trim()).collect();
+        let source = match parts.pop() {
+            Some(source) => source,
+            None => return Err(format!("Missing Input in Binding {}", text)),
+        };
+        let source = if source.starts_with("Mouse:") {
+            match mouse_button_from_name(&source["Mouse:".len()..]) {
+                Some(mouse_button) => InputSource::Mouse(mouse_button),
+                None => return Err(format!("Unknown Mouse Button {}", source)),
+            }
+        } else {
+            match key_code_from_name(source) {
+                Some(key_code) => InputSource::Key(key_code),
+                None => return Err(format!("Unknown Key {}", source)),
+            }
+        };
+        let mut modifiers = vec!();
+        for part in parts {
+            match key_code_from_name(part) {
+                Some(key_code) => modifiers.push(key_code),
+                None => return Err(format!("Unknown Modifier {}", part)),
+            }
+        }
+        Ok(Binding {
+            source: source,
+            modifiers: modifiers,
+        })
+    }
+}
+
+impl Display for Binding {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        for modifier in self.modifiers.iter() {
+            write!(f, "{}+", key_code_name(*modifier))?;
+        }
+        match self.source {
+            InputSource::Key(key_code) => write!(f, "{}", key_code_name(key_code)),
+            InputSource::Mouse(mouse_button) => write!(f, "Mouse:{}", mouse_button_name(mouse_button)),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Action {
+    Button(Vec<Binding>),
+    Axis(Vec<(Binding, f32)>),
+}
+
+#[derive(Debug)]
+pub enum ActionError {
+    Io(io::Error),
+    Parse(usize, String),
+}
+
+impl Display for ActionError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            ActionError::Io(ref err) => write!(f, "Unable to Read Bindings: {}", err),
+            ActionError::Parse(line, ref message) => write!(f, "Unable to Parse Bindings on Line {}: {}", line, message),
+        }
+    }
+}
+
+impl StdError for ActionError {
+    fn description(&self) -> &str {
+        match *self {
+            ActionError::Io(_) => "Unable to Read Bindings",
+            ActionError::Parse(_, _) => "Unable to Parse Bindings",
+        }
+    }
+}
+
+impl From<io::Error> for ActionError {
+    fn from(err: io::Error) -> ActionError {
+        ActionError::Io(err)
+    }
+}
+
+pub struct ActionMap {
+    actions: HashMap<String, Action>,
+}
+
+impl ActionMap {
+    pub fn new() -> ActionMap {
+        ActionMap {
+            actions: HashMap::new(),
+        }
+    }
+
+    pub fn get_action(&self, name: &str) -> Option<&Action> {
+        self.actions.get(name)
+    }
+
+    pub fn bind_button(&mut self, name: &str, binding: Binding) {
+        let action = self.actions.entry(name.to_string()).or_insert_with(|| Action::Button(vec!()));
+        match *action {
+            Action::Button(ref mut bindings) => bindings.push(binding),
+            Action::Axis(ref mut bindings) => bindings.push((binding, 1.0)),
+        }
+    }
+
+    pub fn bind_axis(&mut self, name: &str, binding: Binding, scale: f32) {
+        let action = self.actions.entry(name.to_string()).or_insert_with(|| Action::Axis(vec!()));
+        let converted = match *action {
+            Action::Button(ref mut bindings) => Some(Action::Axis(bindings.drain(..).map(|binding| (binding, 1.0)).collect())),
+            Action::Axis(_) => None,
+        };
+        if let Some(converted) = converted {
+            *action = converted;
+        }
+        if let Action::Axis(ref mut bindings) = *action {
+            bindings.push((binding, scale));
+        }
+    }
+
+    pub fn unbind(&mut self, name: &str) {
+        self.actions.remove(name);
+    }
+
+    pub fn is_active(&self, name: &str, lookup: &Fn(InputSource) -> Button) -> bool {
+        match self.actions.get(name) {
+            Some(&Action::Button(ref bindings)) => bindings.iter().any(|binding| binding.is_pressed(lookup)),
+            Some(&Action::Axis(ref bindings)) => bindings.iter().any(|binding| binding.0.is_pressed(lookup)),
+            None => false,
+        }
+    }
+
+    pub fn just_pressed(&self, name: &str, lookup: &Fn(InputSource) -> Button, tick_number: u64) -> bool {
+        match self.actions.get(name) {
+            Some(&Action::Button(ref bindings)) => bindings.iter().any(|binding| binding.just_pressed(lookup, tick_number)),
+            Some(&Action::Axis(ref bindings)) => bindings.iter().any(|binding| binding.0.just_pressed(lookup, tick_number)),
+            None => false,
+        }
+    }
+
+    pub fn just_released(&self, name: &str, lookup: &Fn(InputSource) -> Button, tick_number: u64) -> bool {
+        match self.actions.get(name) {
+            Some(&Action::Button(ref bindings)) => bindings.iter().any(|binding| binding.just_released(lookup, tick_number)),
+            Some(&Action::Axis(ref bindings)) => bindings.iter().any(|binding| binding.0.just_released(lookup, tick_number)),
+            None => false,
+        }
+    }
+
+    pub fn get_axis(&self, name: &str, lookup: &Fn(InputSource) -> Button) -> f32 {
+        let value = match self.actions.get(name) {
+            Some(&Action::Button(ref bindings)) => if bindings.iter().any(|binding| binding.is_pressed(lookup)) { 1.0 } else { 0.0 },
+            Some(&Action::Axis(ref bindings)) => bindings.iter().filter(|binding| binding.0.is_pressed(lookup)).fold(0.0, |sum, binding| sum + binding.1),
+            None => 0.0,
+        };
+        value.max(-1.0).min(1.0)
+    }
+
+    pub fn parse(text: &str) -> Result<ActionMap, ActionError> {
+        let mut map = ActionMap::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let number = number + 1;
+            let mut sides = line.splitn(2, '=');
+            let head: Vec<&str> = sides.next().unwrap_or("").split_whitespace().collect();
+            let body = match sides.next() {
+                Some(body) => body,
+                None => return Err(ActionError::Parse(number, "Missing =".to_string())),
+            };
+            if head.len() != 2 {
+                return Err(ActionError::Parse(number, "Expected Kind and Name before =".to_string()));
+            }
+            let name = head[1];
+            let is_axis = match head[0] {
+                "button" => false,
+                "axis" => true,
+                kind => return Err(ActionError::Parse(number, format!("Unknown Action Kind {}", kind))),
+            };
+            if map.actions.contains_key(name) {
+                return Err(ActionError::Parse(number, format!("Duplicate Action {}", name)));
+            }
+            map.actions.insert(name.to_string(), if is_axis { Action::Axis(vec!()) } else { Action::Button(vec!()) });
+            for entry in body.split(',').map(|entry| entry.trim()).filter(|entry| !entry.is_empty()) {
+                let mut words = entry.split_whitespace();
+                let binding = Binding::parse(words.next().unwrap_or("")).map_err(|message| ActionError::Parse(number, message))?;
+                if is_axis {
+                    let scale = match words.next() {
+                        Some(scale) => scale.parse().map_err(|_| ActionError::Parse(number, format!("Invalid Scale {}", scale)))?,
+                        None => 1.0,
+                    };
+                    map.bind_axis(name, binding, scale);
+                } else {
+                    map.bind_button(name, binding);
+                }
+            }
+        }
+        Ok(map)
+    }
+
+    pub fn load(path: &Path) -> Result<ActionMap, ActionError> {
+        let mut text = String::new();
+        File::open(path)?.read_to_string(&mut text)?;
+        ActionMap::parse(&text)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ActionError> {
+        File::create(path)?.write_all(self.to_string().as_bytes())?;
+        Ok(())
+    }
+}
+
+impl Display for ActionMap {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let mut names: Vec<&String> = self.actions.keys().collect();
+        names.sort();
+        for name in names {
+            match *self.actions.get(name).expect("Unable to Get Action in Display in Action Map") {
+                Action::Button(ref bindings) => {
+                    let bindings: Vec<String> = bindings.iter().map(|binding| binding.to_string()).collect();
+                    writeln!(f, "button {} = {}", name, bindings.join(", "))?;
+                },
+                Action::Axis(ref bindings) => {
+                    let bindings: Vec<String> = bindings.iter().map(|binding| format!("{} {}", binding.0, binding.1)).collect();
+                    writeln!(f, "axis {} = {}", name, bindings.join(", "))?;
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use input::{Button, ActionMap, ActionError, Action, Binding, InputSource};
+    use utils::{KeyCode, ButtonState};
+
+    fn lookup(source: InputSource) -> Button {
+        match source {
+            InputSource::Key(KeyCode::A) | InputSource::Key(KeyCode::D) => Button::new(0, ButtonState::Pressed),
+            _ => Button::new(0, ButtonState::Released),
+        }
+    }
+
+    #[test]
+    fn bind_axis_converts_a_button_action_to_an_axis() {
+        let mut map = ActionMap::new();
+        map.bind_button("move", Binding::key(KeyCode::D));
+        map.bind_axis("move", Binding::key(KeyCode::A), -0.25);
+        assert_eq!(map.get_action("move"), Some(&Action::Axis(vec!((Binding::key(KeyCode::D), 1.0), (Binding::key(KeyCode::A), -0.25)))));
+        assert_eq!(map.get_axis("move", &lookup), 0.75);
+    }
+
+    #[test]
+    fn bind_button_on_an_axis_uses_unit_scale() {
+        let mut map = ActionMap::new();
+        map.bind_axis("move", Binding::key(KeyCode::A), -1.0);
+        map.bind_button("move", Binding::key(KeyCode::D));
+        assert_eq!(map.get_axis("move", &lookup), 0.0);
+    }
+
+    #[test]
+    fn parse_round_trips_through_display() {
+        let map = ActionMap::parse("axis move = A -1, D 1\nbutton jump = LShift+Space, Mouse:Left\n").expect("Unable to Parse Map in Test");
+        assert_eq!(ActionMap::parse(&map.to_string()).expect("Unable to Reparse Map in Test").to_string(), map.to_string());
+        assert!(ActionMap::parse("button jump = Mute").is_ok());
+    }
+
+    #[test]
+    fn parse_rejects_duplicate_actions() {
+        for text in ["button jump = Space\nbutton jump = W", "button jump = Space\n\naxis jump = W 1"].iter() {
+            match ActionMap::parse(text) {
+                Err(ActionError::Parse(line, message)) => {
+                    assert_eq!(line, text.lines().count());
+                    assert_eq!(message, "Duplicate Action jump");
+                },
+                _ => panic!("Expected Duplicate Action Error for {}", text),
+            }
+        }
+        assert!(ActionMap::parse("button jump = Space\nbutton fire = W").is_ok());
+    }
+
+    #[test]
+    fn modifiers_apply_to_presses_and_releases() {
+        let binding = Binding::key(KeyCode::S).with_modifier(KeyCode::LControl);
+        let released = |held: bool| move |source: InputSource| match source {
+            InputSource::Key(KeyCode::LControl) if held => Button::new(0, ButtonState::Pressed),
+            _ => Button::new(1, ButtonState::Pressed).update(Button::new(3, ButtonState::Released)),
+        };
+        assert!(binding.just_released(&released(true), 3));
+        assert!(!binding.just_released(&released(false), 3));
+        assert!(Binding::key(KeyCode::S).just_released(&released(false), 3));
+    }
+}
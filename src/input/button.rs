@@ -4,6 +4,8 @@ use utils::{ButtonState};
 pub struct Button {
     tick_number: u64,
     state: ButtonState,
+    press_tick: Option<u64>,
+    previous_press_tick: Option<u64>,
 }
 
 #[allow(dead_code)]
@@ -12,6 +14,11 @@ impl Button {
         Button {
             tick_number: tick_number,
             state: state,
+            press_tick: match state {
+                ButtonState::Pressed => Some(tick_number),
+                ButtonState::Released => None,
+            },
+            previous_press_tick: None,
         }
     }
 
@@ -22,4 +29,93 @@ impl Button {
     pub fn get_state(&self) -> ButtonState {
         self.state
     }
+
+    pub fn get_press_tick(&self) -> Option<u64> {
+        self.press_tick
+    }
+
+    pub fn get_previous_press_tick(&self) -> Option<u64> {
+        self.previous_press_tick
+    }
+
+    pub fn update(&self, next: Button) -> Button {
+        if next.state == self.state {
+            return *self;
+        }
+        match next.state {
+            ButtonState::Pressed => Button {
+                tick_number: next.tick_number,
+                state: next.state,
+                press_tick: Some(next.tick_number),
+                previous_press_tick: self.press_tick,
+            },
+            ButtonState::Released => Button {
+                tick_number: next.tick_number,
+                state: next.state,
+                press_tick: self.press_tick,
+                previous_press_tick: self.previous_press_tick,
+            },
+        }
+    }
+
+    pub fn is_pressed(&self) -> bool {
+        self.state == ButtonState::Pressed
+    }
+
+    pub fn just_pressed(&self, tick_number: u64) -> bool {
+        self.is_pressed() && self.tick_number == tick_number
+    }
+
+    pub fn just_released(&self, tick_number: u64) -> bool {
+        !self.is_pressed() && self.press_tick.is_some() && self.tick_number == tick_number
+    }
+
+    pub fn get_held_ticks(&self, tick_number: u64) -> u64 {
+        if self.is_pressed() && tick_number >= self.tick_number {
+            tick_number - self.tick_number
+        } else {
+            0
+        }
+    }
+
+    pub fn held_for(&self, tick_number: u64, ticks: u64) -> bool {
+        self.is_pressed() && self.get_held_ticks(tick_number) >= ticks
+    }
+
+    pub fn double_pressed(&self, tick_number: u64, window: u64) -> bool {
+        if !self.just_pressed(tick_number) {
+            return false;
+        }
+        match self.previous_press_tick {
+            Some(previous) => tick_number.checked_sub(previous).map_or(false, |delta| delta <= window),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use input::button::{Button};
+    use utils::{ButtonState};
+
+    fn press_release_press(first: u64, release: u64, second: u64) -> Button {
+        Button::new(first, ButtonState::Pressed)
+            .update(Button::new(release, ButtonState::Released))
+            .update(Button::new(second, ButtonState::Pressed))
+    }
+
+    #[test]
+    fn double_pressed_within_window() {
+        let button = press_release_press(2, 3, 5);
+        assert!(button.double_pressed(5, 3));
+        assert!(!button.double_pressed(5, 2));
+        assert!(!button.double_pressed(6, 3));
+    }
+
+    #[test]
+    fn double_pressed_ignores_out_of_order_presses() {
+        let button = press_release_press(9, 3, 4);
+        assert_eq!(button.get_previous_press_tick(), Some(9));
+        assert!(!button.double_pressed(4, 10));
+    }
 }
@@ -0,0 +1,203 @@
+use std::collections::{HashSet};
+
+use input::{InputEvent};
+use math::{Vec2, Rect};
+use utils::{KeyCode, MouseButton};
+
+pub type ContextId = u64;
+
+pub struct InputContext {
+    id: ContextId,
+    name: String,
+    keys: HashSet<KeyCode>,
+    buttons: HashSet<MouseButton>,
+    all_keys: bool,
+    regions: Vec<Rect>,
+    consumed_keys: HashSet<KeyCode>,
+    consumed_buttons: HashSet<MouseButton>,
+}
+
+impl InputContext {
+    fn new(id: ContextId, name: &str) -> InputContext {
+        InputContext {
+            id: id,
+            name: name.to_string(),
+            keys: HashSet::new(),
+            buttons: HashSet::new(),
+            all_keys: false,
+            regions: vec!(),
+            consumed_keys: HashSet::new(),
+            consumed_buttons: HashSet::new(),
+        }
+    }
+
+    pub fn get_id(&self) -> ContextId {
+        self.id
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn capture_key(&mut self, key_code: KeyCode) {
+        self.keys.insert(key_code);
+    }
+
+    pub fn release_key(&mut self, key_code: KeyCode) {
+        self.keys.remove(&key_code);
+    }
+
+    pub fn capture_all_keys(&mut self, all_keys: bool) {
+        self.all_keys = all_keys;
+    }
+
+    pub fn capture_button(&mut self, mouse_button: MouseButton) {
+        self.buttons.insert(mouse_button);
+    }
+
+    pub fn release_button(&mut self, mouse_button: MouseButton) {
+        self.buttons.remove(&mouse_button);
+    }
+
+    pub fn add_region(&mut self, min: Vec2, max: Vec2) {
+        self.regions.push(Rect::new(min, max));
+    }
+
+    pub fn clear_regions(&mut self) {
+        self.regions.clear();
+    }
+
+    pub fn consume_key(&mut self, key_code: KeyCode) {
+        self.consumed_keys.insert(key_code);
+    }
+
+    pub fn consume_button(&mut self, mouse_button: MouseButton) {
+        self.consumed_buttons.insert(mouse_button);
+    }
+
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        self.regions.iter().any(|region| region.contains_point(point))
+    }
+
+    fn takes_key(&self, key_code: KeyCode) -> bool {
+        self.all_keys || self.keys.contains(&key_code) || self.consumed_keys.contains(&key_code)
+    }
+
+    fn takes_button(&self, mouse_button: MouseButton, mouse_position: Vec2) -> bool {
+        self.buttons.contains(&mouse_button) || self.consumed_buttons.contains(&mouse_button) || self.contains_point(mouse_position)
+    }
+}
+
+pub struct InputStack {
+    contexts: Vec<InputContext>,
+    next_id: ContextId,
+}
+
+impl InputStack {
+    pub fn new() -> InputStack {
+        InputStack {
+            contexts: vec!(),
+            next_id: 0,
+        }
+    }
+
+    pub fn push(&mut self, name: &str) -> ContextId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.contexts.push(InputContext::new(id, name));
+        id
+    }
+
+    pub fn remove(&mut self, id: ContextId) {
+        self.contexts.retain(|context| context.id != id);
+    }
+
+    pub fn get_context(&self, id: ContextId) -> Option<&InputContext> {
+        self.contexts.iter().find(|context| context.id == id)
+    }
+
+    pub fn get_context_mut(&mut self, id: ContextId) -> Option<&mut InputContext> {
+        self.contexts.iter_mut().find(|context| context.id == id)
+    }
+
+    pub fn get_top(&self) -> Option<ContextId> {
+        self.contexts.last().map(|context| context.id)
+    }
+
+    pub fn begin_tick(&mut self) {
+        for context in self.contexts.iter_mut() {
+            context.consumed_keys.clear();
+            context.consumed_buttons.clear();
+        }
+    }
+
+    fn above(&self, id: Option<ContextId>) -> &[InputContext] {
+        match id.and_then(|id| self.contexts.iter().position(|context| context.id == id)) {
+            Some(position) => &self.contexts[position + 1..],
+            None => &self.contexts[..],
+        }
+    }
+
+    pub fn is_key_captured(&self, id: Option<ContextId>, key_code: KeyCode) -> bool {
+        self.above(id).iter().any(|context| context.takes_key(key_code))
+    }
+
+    pub fn is_button_captured(&self, id: Option<ContextId>, mouse_button: MouseButton, mouse_position: Vec2) -> bool {
+        self.above(id).iter().any(|context| context.takes_button(mouse_button, mouse_position))
+    }
+
+    pub fn is_mouse_captured(&self, id: Option<ContextId>, mouse_position: Vec2) -> bool {
+        self.above(id).iter().any(|context| context.contains_point(mouse_position))
+    }
+
+    pub fn is_text_captured(&self, id: Option<ContextId>) -> bool {
+        self.above(id).iter().any(|context| context.all_keys)
+    }
+
+    pub fn is_event_captured(&self, id: Option<ContextId>, event: &InputEvent, mouse_position: Vec2) -> bool {
+        match *event {
+            InputEvent::Key(key_code, _) => self.is_key_captured(id, key_code),
+            InputEvent::Text(_) => self.is_text_captured(id),
+            InputEvent::MouseButton(mouse_button, _) => self.is_button_captured(id, mouse_button, mouse_position),
+            InputEvent::MouseMoved(position) => self.is_mouse_captured(id, position),
+            InputEvent::MouseWheel(_) => self.is_mouse_captured(id, mouse_position),
+            InputEvent::Touch(_, _, position) => self.is_mouse_captured(id, position),
+            InputEvent::DroppedFile(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use input::{InputStack, InputEvent};
+    use math::{Vec2};
+    use utils::{KeyCode, MouseButton, ButtonState, TouchPhase};
+
+    #[test]
+    fn events_are_filtered_by_the_contexts_above() {
+        let mut stack = InputStack::new();
+        let game = stack.push("game");
+        let menu = stack.push("menu");
+        {
+            let menu = stack.get_context_mut(menu).expect("Unable to Get Menu in Test");
+            menu.capture_key(KeyCode::Escape);
+            menu.add_region(Vec2::zero(), Vec2::from([10.0, 10.0]));
+        }
+        let inside = Vec2::from([5.0, 5.0]);
+        let outside = Vec2::from([50.0, 5.0]);
+        let escape = InputEvent::Key(KeyCode::Escape, ButtonState::Pressed);
+        let click = InputEvent::MouseButton(MouseButton::Left, ButtonState::Pressed);
+        assert!(stack.is_event_captured(Some(game), &escape, outside));
+        assert!(!stack.is_event_captured(Some(menu), &escape, outside));
+        assert!(!stack.is_event_captured(Some(game), &InputEvent::Key(KeyCode::A, ButtonState::Pressed), outside));
+        assert!(stack.is_event_captured(Some(game), &click, inside));
+        assert!(!stack.is_event_captured(Some(game), &click, outside));
+        assert!(stack.is_event_captured(Some(game), &InputEvent::MouseMoved(inside), outside));
+        assert!(stack.is_event_captured(Some(game), &InputEvent::Touch(0, TouchPhase::Started, inside), outside));
+        assert!(!stack.is_event_captured(Some(game), &InputEvent::Text('a'), outside));
+        stack.get_context_mut(menu).expect("Unable to Get Menu in Test").capture_all_keys(true);
+        assert!(stack.is_event_captured(Some(game), &InputEvent::Text('a'), outside));
+        assert!(stack.is_event_captured(None, &InputEvent::Text('a'), outside));
+        assert!(!stack.is_event_captured(Some(menu), &InputEvent::Text('a'), outside));
+    }
+}
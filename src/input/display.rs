@@ -1,8 +1,12 @@
 use math::{Vec2};
+use utils::{CursorState};
 
 pub struct Display {
     resolution: Vec2,
     aspect_ratio: f32,
+    position: Vec2,
+    cursor_state: CursorState,
+    redraw_requested: bool,
 }
 
 impl Display {
@@ -10,6 +14,9 @@ impl Display {
         Display {
             aspect_ratio: resolution[0] / resolution[1],
             resolution: resolution,
+            position: Vec2::zero(),
+            cursor_state: CursorState::Normal,
+            redraw_requested: false,
         }
     }
 
@@ -25,4 +32,32 @@ impl Display {
     pub fn get_aspect_ratio(&self) -> f32 {
         self.aspect_ratio
     }
+
+    pub fn set_position(&mut self, position: Vec2) {
+        self.position = position;
+    }
+
+    pub fn get_position(&self) -> Vec2 {
+        self.position
+    }
+
+    pub fn set_cursor_state(&mut self, cursor_state: CursorState) {
+        self.cursor_state = cursor_state;
+    }
+
+    pub fn get_cursor_state(&self) -> CursorState {
+        self.cursor_state
+    }
+
+    pub fn request_redraw(&mut self) {
+        self.redraw_requested = true;
+    }
+
+    pub fn is_redraw_requested(&self) -> bool {
+        self.redraw_requested
+    }
+
+    pub fn clear_redraw_request(&mut self) {
+        self.redraw_requested = false;
+    }
 }
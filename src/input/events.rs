@@ -0,0 +1,69 @@
+use std::path::{PathBuf};
+
+use math::{Vec2};
+use utils::{KeyCode, MouseButton, ButtonState, TouchPhase};
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum InputEvent {
+    Key(KeyCode, ButtonState),
+    MouseButton(MouseButton, ButtonState),
+    MouseMoved(Vec2),
+    MouseWheel(Vec2),
+    Text(char),
+    DroppedFile(PathBuf),
+    Touch(u64, TouchPhase, Vec2),
+}
+
+pub struct EventLog {
+    tick_number: u64,
+    events: Vec<InputEvent>,
+}
+
+impl EventLog {
+    pub fn new() -> EventLog {
+        EventLog {
+            tick_number: 0,
+            events: vec!(),
+        }
+    }
+
+    pub fn get_tick_number(&self) -> u64 {
+        self.tick_number
+    }
+
+    pub fn get_events(&self) -> &Vec<InputEvent> {
+        &self.events
+    }
+
+    pub fn push(&mut self, event: InputEvent) {
+        self.events.push(event);
+    }
+
+    pub fn begin_tick(&mut self, tick_number: u64) {
+        self.tick_number = tick_number;
+        self.events.clear();
+    }
+
+    pub fn count_key_presses(&self, key_code: KeyCode) -> usize {
+        self.events.iter().filter(|event| **event == InputEvent::Key(key_code, ButtonState::Pressed)).count()
+    }
+
+    pub fn count_key_releases(&self, key_code: KeyCode) -> usize {
+        self.events.iter().filter(|event| **event == InputEvent::Key(key_code, ButtonState::Released)).count()
+    }
+
+    pub fn count_mouse_presses(&self, mouse_button: MouseButton) -> usize {
+        self.events.iter().filter(|event| **event == InputEvent::MouseButton(mouse_button, ButtonState::Pressed)).count()
+    }
+
+    pub fn count_mouse_releases(&self, mouse_button: MouseButton) -> usize {
+        self.events.iter().filter(|event| **event == InputEvent::MouseButton(mouse_button, ButtonState::Released)).count()
+    }
+
+    pub fn get_text(&self) -> String {
+        self.events.iter().filter_map(|event| match *event {
+            InputEvent::Text(character) => Some(character),
+            _ => None,
+        }).collect()
+    }
+}
@@ -0,0 +1,34 @@
+use std::collections::{VecDeque};
+use std::path::{PathBuf};
+
+pub struct DroppedFiles {
+    paths: VecDeque<PathBuf>,
+}
+
+impl DroppedFiles {
+    pub fn new() -> DroppedFiles {
+        DroppedFiles {
+            paths: VecDeque::new(),
+        }
+    }
+
+    pub fn push(&mut self, path: PathBuf) {
+        self.paths.push_back(path);
+    }
+
+    pub fn pop(&mut self) -> Option<PathBuf> {
+        self.paths.pop_front()
+    }
+
+    pub fn take_all(&mut self) -> Vec<PathBuf> {
+        self.paths.drain(..).collect()
+    }
+
+    pub fn get_count(&self) -> usize {
+        self.paths.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+}
@@ -22,6 +22,10 @@ impl Keyboard {
     }
 
     pub fn set_key_state(&mut self, key_code: KeyCode, key: Button) {
+        let key = match self.keys.get(&key_code) {
+            Some(old) => old.update(key),
+            None => key,
+        };
         self.keys.insert(key_code, key);
     }
 }
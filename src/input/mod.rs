@@ -2,8 +2,22 @@ mod keyboard;
 mod mouse;
 mod display;
 mod button;
+mod actions;
+mod events;
+mod text;
+mod files;
+mod touch;
+mod recording;
+mod contexts;
 
 pub use self::keyboard::{Keyboard};
 pub use self::mouse::{Mouse};
 pub use self::display::{Display};
 pub use self::button::{Button};
+pub use self::actions::{ActionMap, Action, ActionError, Binding, InputSource};
+pub use self::events::{InputEvent, EventLog};
+pub use self::text::{TextInput};
+pub use self::files::{DroppedFiles};
+pub use self::touch::{Touches, TouchPoint};
+pub use self::recording::{Recorder, Replay, RecordingError};
+pub use self::contexts::{InputStack, InputContext, ContextId};
@@ -4,9 +4,14 @@ use math::{Vec2};
 use input::{Button};
 use utils::{MouseButton, ButtonState};
 
+const PIXELS_PER_LINE: f32 = 20.0;
+
 pub struct Mouse {
     buttons: HashMap<MouseButton, Button>,
     position: Vec2,
+    scroll: Vec2,
+    delta: Vec2,
+    positioned: bool,
 }
 
 impl Mouse {
@@ -14,10 +19,17 @@ impl Mouse {
         Mouse {
             buttons: HashMap::new(),
             position: Vec2::zero(),
+            scroll: Vec2::zero(),
+            delta: Vec2::zero(),
+            positioned: false,
         }
     }
 
     pub fn set_mouse_button(&mut self, button: MouseButton, state: Button) {
+        let state = match self.buttons.get(&button) {
+            Some(old) => old.update(state),
+            None => state,
+        };
         self.buttons.insert(button, state);
     }
 
@@ -29,10 +41,39 @@ impl Mouse {
     }
 
     pub fn set_mouse_position(&mut self, pos: Vec2) {
+        if self.positioned {
+            self.delta = self.delta + (pos - self.position);
+        }
         self.position = pos;
+        self.positioned = true;
+    }
+
+    pub fn get_delta(&self) -> Vec2 {
+        self.delta
+    }
+
+    pub fn get_ndc(&self, resolution: Vec2) -> Vec2 {
+        Vec2::from([2.0 * self.position[0] / resolution[0] - 1.0, 1.0 - 2.0 * self.position[1] / resolution[1]])
     }
 
     pub fn get_mouse_position(&self) -> Vec2 {
         self.position
     }
+
+    pub fn add_scroll(&mut self, scroll: Vec2) {
+        self.scroll = self.scroll + scroll;
+    }
+
+    pub fn add_pixel_scroll(&mut self, scroll: Vec2) {
+        self.add_scroll(Vec2::from([scroll[0] / PIXELS_PER_LINE, scroll[1] / PIXELS_PER_LINE]));
+    }
+
+    pub fn get_scroll(&self) -> Vec2 {
+        self.scroll
+    }
+
+    pub fn begin_tick(&mut self) {
+        self.scroll = Vec2::zero();
+        self.delta = Vec2::zero();
+    }
 }
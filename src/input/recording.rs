@@ -0,0 +1,312 @@
+use std::collections::{VecDeque};
+use std::error::{Error as StdError};
+use std::fmt::{Display, Formatter, Error};
+use std::fs::{File};
+use std::io::{BufWriter, Read, Write};
+use std::io;
+use std::path::{Path, PathBuf};
+use glium::glutin::Event as WindowEvent;
+use glium::glutin::{MouseScrollDelta, Touch};
+
+use utils::{ButtonState, TouchPhase, key_code_name, key_code_from_name, mouse_button_name, mouse_button_from_name};
+
+#[derive(Debug)]
+pub enum RecordingError {
+    Io(io::Error),
+    Parse(usize, String),
+}
+
+impl Display for RecordingError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            RecordingError::Io(ref err) => write!(f, "Unable to Read Recording: {}", err),
+            RecordingError::Parse(line, ref message) => write!(f, "Unable to Parse Recording on Line {}: {}", line, message),
+        }
+    }
+}
+
+impl StdError for RecordingError {
+    fn description(&self) -> &str {
+        match *self {
+            RecordingError::Io(_) => "Unable to Read Recording",
+            RecordingError::Parse(_, _) => "Unable to Parse Recording",
+        }
+    }
+}
+
+impl From<io::Error> for RecordingError {
+    fn from(err: io::Error) -> RecordingError {
+        RecordingError::Io(err)
+    }
+}
+
+pub struct Recorder {
+    writer: BufWriter<File>,
+    start_tick: u64,
+}
+
+impl Recorder {
+    pub fn create(path: &Path, start_tick: u64, tick_rate: f64, seed: u64) -> io::Result<Recorder> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "tick_rate {}", tick_rate)?;
+        writeln!(writer, "seed {}", seed)?;
+        Ok(Recorder {
+            writer: writer,
+            start_tick: start_tick,
+        })
+    }
+
+    pub fn record(&mut self, tick_number: u64, event: &WindowEvent) -> io::Result<()> {
+        match format_event(event) {
+            Some(line) => writeln!(self.writer, "{} {}", tick_number - self.start_tick, line),
+            None => Ok(()),
+        }
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+pub struct Replay {
+    tick_rate: f64,
+    seed: u64,
+    start_tick: u64,
+    events: VecDeque<(u64, WindowEvent)>,
+}
+
+impl Replay {
+    pub fn load(path: &Path) -> Result<Replay, RecordingError> {
+        let mut text = String::new();
+        File::open(path)?.read_to_string(&mut text)?;
+        Replay::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Replay, RecordingError> {
+        let mut replay = Replay {
+            tick_rate: 60.0,
+            seed: 0,
+            start_tick: 0,
+            events: VecDeque::new(),
+        };
+        for (number, line) in text.lines().enumerate() {
+            let number = number + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut words = line.splitn(2, ' ');
+            let head = words.next().unwrap_or("");
+            let rest = words.next().unwrap_or("").trim();
+            match head {
+                "tick_rate" => {
+                    let tick_rate: f64 = rest.parse().map_err(|_| RecordingError::Parse(number, format!("Invalid Tick Rate {}", rest)))?;
+                    if !(tick_rate > 0.0 && tick_rate.is_finite()) {
+                        return Err(RecordingError::Parse(number, format!("Invalid Tick Rate {}", rest)));
+                    }
+                    replay.tick_rate = tick_rate;
+                },
+                "seed" => replay.seed = rest.parse().map_err(|_| RecordingError::Parse(number, format!("Invalid Seed {}", rest)))?,
+                tick => {
+                    let tick = tick.parse().map_err(|_| RecordingError::Parse(number, format!("Invalid Tick {}", tick)))?;
+                    let event = parse_event(rest).map_err(|message| RecordingError::Parse(number, message))?;
+                    replay.events.push_back((tick, event));
+                },
+            }
+        }
+        Ok(replay)
+    }
+
+    pub fn get_tick_rate(&self) -> f64 {
+        self.tick_rate
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn set_start_tick(&mut self, start_tick: u64) {
+        self.start_tick = start_tick;
+    }
+
+    pub fn take_tick(&mut self, tick_number: u64) -> Vec<WindowEvent> {
+        let mut events = vec!();
+        while self.events.front().map_or(false, |entry| entry.0 + self.start_tick <= tick_number) {
+            events.push(self.events.pop_front().expect("Unable to Pop Event in Take Tick in Replay").1);
+        }
+        events
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+fn state_name(state: ButtonState) -> &'static str {
+    match state {
+        ButtonState::Pressed => "Pressed",
+        ButtonState::Released => "Released",
+    }
+}
+
+fn state_from_name(name: &str) -> Result<ButtonState, String> {
+    match name {
+        "Pressed" => Ok(ButtonState::Pressed),
+        "Released" => Ok(ButtonState::Released),
+        _ => Err(format!("Unknown Button State {}", name)),
+    }
+}
+
+fn phase_name(phase: TouchPhase) -> &'static str {
+    match phase {
+        TouchPhase::Started => "Started",
+        TouchPhase::Moved => "Moved",
+        TouchPhase::Ended => "Ended",
+        TouchPhase::Cancelled => "Cancelled",
+    }
+}
+
+fn phase_from_name(name: &str) -> Result<TouchPhase, String> {
+    match name {
+        "Started" => Ok(TouchPhase::Started),
+        "Moved" => Ok(TouchPhase::Moved),
+        "Ended" => Ok(TouchPhase::Ended),
+        "Cancelled" => Ok(TouchPhase::Cancelled),
+        _ => Err(format!("Unknown Touch Phase {}", name)),
+    }
+}
+
+fn format_event(event: &WindowEvent) -> Option<String> {
+    let line = match *event {
+        WindowEvent::Resized(width, height) => format!("resize {} {}", width, height),
+        WindowEvent::Moved(x, y) => format!("move {} {}", x, y),
+        WindowEvent::DroppedFile(ref path) => format!("drop {}", path.display()),
+        WindowEvent::ReceivedCharacter(character) => format!("char {}", character as u32),
+        WindowEvent::KeyboardInput(state, scan_code, key_code) => format!("key {} {} {}", state_name(state), scan_code, match key_code {
+            Some(key_code) => key_code_name(key_code),
+            None => "-".to_string(),
+        }),
+        WindowEvent::MouseMoved((x, y)) => format!("cursor {} {}", x, y),
+        WindowEvent::MouseWheel(MouseScrollDelta::LineDelta(x, y)) => format!("wheel line {} {}", x, y),
+        WindowEvent::MouseWheel(MouseScrollDelta::PixelDelta(x, y)) => format!("wheel pixel {} {}", x, y),
+        WindowEvent::MouseInput(state, mouse_button) => format!("button {} {}", state_name(state), mouse_button_name(mouse_button)),
+        WindowEvent::Touch(touch) => format!("touch {} {} {} {}", touch.id, phase_name(touch.phase), touch.location.0, touch.location.1),
+        WindowEvent::Refresh => "refresh".to_string(),
+        _ => return None,
+    };
+    Some(line)
+}
+
+fn parse_event(line: &str) -> Result<WindowEvent, String> {
+    let mut words = line.splitn(2, ' ');
+    let kind = words.next().unwrap_or("");
+    let rest = words.next().unwrap_or("");
+    if kind == "drop" {
+        return Ok(WindowEvent::DroppedFile(PathBuf::from(rest)));
+    }
+    let args: Vec<&str> = rest.split_whitespace().collect();
+    let arg = |index: usize| -> Result<&str, String> {
+        args.get(index).cloned().ok_or(format!("Missing Argument {} in {}", index, line))
+    };
+    macro_rules! number {
+        ($index:expr) => {
+            arg($index)?.parse().map_err(|_| format!("Invalid Number in {}", line))?
+        }
+    }
+    let event = match kind {
+        "resize" => WindowEvent::Resized(number!(0), number!(1)),
+        "move" => WindowEvent::Moved(number!(0), number!(1)),
+        "char" => {
+            let code: u32 = number!(0);
+            match ::std::char::from_u32(code) {
+                Some(character) => WindowEvent::ReceivedCharacter(character),
+                None => return Err(format!("Invalid Character in {}", line)),
+            }
+        },
+        "key" => {
+            let key_code = match arg(2)? {
+                "-" => None,
+                name => match key_code_from_name(name) {
+                    Some(key_code) => Some(key_code),
+                    None => return Err(format!("Unknown Key {}", name)),
+                },
+            };
+            WindowEvent::KeyboardInput(state_from_name(arg(0)?)?, number!(1), key_code)
+        },
+        "cursor" => WindowEvent::MouseMoved((number!(0), number!(1))),
+        "wheel" => match arg(0)? {
+            "line" => WindowEvent::MouseWheel(MouseScrollDelta::LineDelta(number!(1), number!(2))),
+            "pixel" => WindowEvent::MouseWheel(MouseScrollDelta::PixelDelta(number!(1), number!(2))),
+            unit => return Err(format!("Unknown Scroll Unit {}", unit)),
+        },
+        "button" => match mouse_button_from_name(arg(1)?) {
+            Some(mouse_button) => WindowEvent::MouseInput(state_from_name(arg(0)?)?, mouse_button),
+            None => return Err(format!("Unknown Mouse Button {}", arg(1)?)),
+        },
+        "touch" => WindowEvent::Touch(Touch {
+            id: number!(0),
+            phase: phase_from_name(arg(1)?)?,
+            location: (number!(2), number!(3)),
+        }),
+        "refresh" => WindowEvent::Refresh,
+        _ => return Err(format!("Unknown Event {}", kind)),
+    };
+    Ok(event)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{PathBuf};
+    use glium::glutin::Event as WindowEvent;
+    use glium::glutin::{MouseScrollDelta, Touch};
+
+    use input::recording::{format_event, parse_event};
+    use input::{Replay, RecordingError};
+    use utils::{ButtonState, KeyCode, MouseButton, TouchPhase};
+
+    #[test]
+    fn events_round_trip_through_their_lines() {
+        let events = vec!(
+            WindowEvent::Resized(640, 480),
+            WindowEvent::Moved(-3, 4),
+            WindowEvent::DroppedFile(PathBuf::from("some dir/file.png")),
+            WindowEvent::ReceivedCharacter('é'),
+            WindowEvent::KeyboardInput(ButtonState::Pressed, 12, Some(KeyCode::Mute)),
+            WindowEvent::KeyboardInput(ButtonState::Released, 7, None),
+            WindowEvent::MouseMoved((10, -20)),
+            WindowEvent::MouseWheel(MouseScrollDelta::LineDelta(0.0, -1.5)),
+            WindowEvent::MouseWheel(MouseScrollDelta::PixelDelta(2.0, 3.0)),
+            WindowEvent::MouseInput(ButtonState::Pressed, MouseButton::Other(4)),
+            WindowEvent::Touch(Touch { phase: TouchPhase::Moved, location: (1.5, 2.5), id: 3 }),
+            WindowEvent::Refresh,
+        );
+        for event in events {
+            let line = format_event(&event).expect("Unable to Format Event in Test");
+            let parsed = parse_event(&line).expect("Unable to Parse Event in Test");
+            assert_eq!(format_event(&parsed), Some(line));
+        }
+    }
+
+    #[test]
+    fn parse_rejects_non_positive_tick_rates() {
+        for text in ["tick_rate 0", "tick_rate -30", "tick_rate inf", "tick_rate NaN"].iter() {
+            match Replay::parse(text) {
+                Err(RecordingError::Parse(1, _)) => (),
+                _ => panic!("Expected Parse Error for {}", text),
+            }
+        }
+        assert_eq!(Replay::parse("tick_rate 30").expect("Unable to Parse Replay in Test").get_tick_rate(), 30.0);
+    }
+
+    #[test]
+    fn take_tick_returns_events_relative_to_the_start_tick() {
+        let mut replay = Replay::parse("seed 9\n0 char 97\n2 char 98\n2 char 99\n").expect("Unable to Parse Replay in Test");
+        assert_eq!(replay.get_seed(), 9);
+        replay.set_start_tick(10);
+        assert!(replay.take_tick(9).is_empty());
+        assert_eq!(replay.take_tick(10).len(), 1);
+        assert!(replay.take_tick(11).is_empty());
+        assert_eq!(replay.take_tick(12).len(), 2);
+        assert!(replay.is_finished());
+    }
+}
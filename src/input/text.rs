@@ -0,0 +1,36 @@
+pub struct TextInput {
+    buffer: String,
+}
+
+impl TextInput {
+    pub fn new() -> TextInput {
+        TextInput {
+            buffer: String::new(),
+        }
+    }
+
+    pub fn push_char(&mut self, character: char) {
+        match character {
+            '\u{8}' => {
+                self.buffer.pop();
+            },
+            '\r' => self.buffer.push('\n'),
+            character if character.is_control() && character != '\n' && character != '\t' => (),
+            character => self.buffer.push(character),
+        }
+    }
+
+    pub fn get_text(&self) -> &str {
+        &self.buffer
+    }
+
+    pub fn take_text(&mut self) -> String {
+        let text = self.buffer.clone();
+        self.buffer.clear();
+        text
+    }
+
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::{HashMap};
+
+use math::{Vec2};
+use utils::{TouchPhase};
+
+#[derive(Copy, Clone, Debug)]
+pub struct TouchPoint {
+    id: u64,
+    phase: TouchPhase,
+    position: Vec2,
+    start_position: Vec2,
+    start_tick: u64,
+}
+
+impl TouchPoint {
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn get_phase(&self) -> TouchPhase {
+        self.phase
+    }
+
+    pub fn get_position(&self) -> Vec2 {
+        self.position
+    }
+
+    pub fn get_start_position(&self) -> Vec2 {
+        self.start_position
+    }
+
+    pub fn get_start_tick(&self) -> u64 {
+        self.start_tick
+    }
+
+    pub fn get_offset(&self) -> Vec2 {
+        self.position - self.start_position
+    }
+}
+
+pub struct Touches {
+    points: HashMap<u64, TouchPoint>,
+}
+
+impl Touches {
+    pub fn new() -> Touches {
+        Touches {
+            points: HashMap::new(),
+        }
+    }
+
+    pub fn update(&mut self, tick_number: u64, id: u64, phase: TouchPhase, position: Vec2) {
+        match phase {
+            TouchPhase::Started => {
+                self.points.insert(id, TouchPoint {
+                    id: id,
+                    phase: phase,
+                    position: position,
+                    start_position: position,
+                    start_tick: tick_number,
+                });
+            },
+            TouchPhase::Moved => match self.points.get_mut(&id) {
+                Some(point) => {
+                    point.phase = phase;
+                    point.position = position;
+                },
+                None => (),
+            },
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                self.points.remove(&id);
+            },
+        }
+    }
+
+    pub fn get_touch(&self, id: u64) -> Option<TouchPoint> {
+        self.points.get(&id).cloned()
+    }
+
+    pub fn get_touches(&self) -> Vec<TouchPoint> {
+        let mut points: Vec<TouchPoint> = self.points.values().cloned().collect();
+        points.sort_by_key(|point| point.id);
+        points
+    }
+
+    pub fn get_count(&self) -> usize {
+        self.points.len()
+    }
+}
@@ -14,11 +14,11 @@ mod logic;
 mod graphics;
 mod utils;
 
-pub use self::math::{Mat4, Vec2, Vec3, Vec4};
-pub use self::input::{Keyboard, Mouse, Display};
-pub use self::graphics::{DrawMethod, Window, Frame, WindowArgs, Transforms, Entity, Vertex, Index, init_vertex, ID, IDType, IDManager};
-pub use self::logic::{Being, BeingType, Game, World, RenderUpdateData};
-pub use self::utils::{KeyCode, ButtonState, MouseButton};
+pub use self::math::{Mat2, Mat3, Mat4, Vec2, Vec3, Vec4, Matrix2, Matrix3, Matrix4, Vector2, Vector3, Vector4, Ray, Quat, Plane, Aabb, Sphere, Frustum, Rect};
+pub use self::input::{Keyboard, Mouse, Display, Button, ActionMap, Action, ActionError, Binding, InputSource, InputEvent, EventLog, TextInput, DroppedFiles, Touches, TouchPoint, Recorder, Replay, RecordingError, InputStack, InputContext, ContextId};
+pub use self::graphics::{DrawMethod, DepthTestMethod, CullingMethod, Window, Frame, WindowArgs, Transforms, Entity, Vertex, Index, init_vertex, ID, IDType, IDManager, Backend, GliumBackend, HeadlessBackend, HeadlessLog, BatchKey, DrawCall, Upload, AssetManager, AssetError, AtlasBuilder, AtlasError, Atlas, UvRect, Camera, Projection};
+pub use self::logic::{Being, BeingType, BeingId, Game, World, RenderUpdateData, Clock, SystemClock, ManualClock};
+pub use self::utils::{KeyCode, ButtonState, MouseButton, TouchPhase, CursorState, WindowEvent, KEY_CODES};
 
 pub fn init() -> Arc<RwLock<IDManager>> {
     graphics::init_vertex();
@@ -1,23 +1,37 @@
 use std::collections::{HashMap};
+use std::hash::{Hash};
+use std::path::{PathBuf};
 use std::sync::{Arc, RwLock};
 
 use math::{Vec3};
-use graphics::{Entity, Transforms, IDManager, Window, Vertex, Index, DrawMethod, method_to_parameters};
-use logic::{World};
+use graphics::{Entity, Transforms, IDManager, Window, Vertex, Index, DrawMethod, AssetError, method_to_parameters};
+use logic::{World, BeingId};
 
-pub trait BeingType { }
+pub trait BeingType: Eq + Hash + Clone + Send + Sync { }
 
 pub trait Being<T: BeingType> : Send + Sync {
     fn get_entities(&self) -> &HashMap<u64, Arc<RwLock<Entity>>>;
     fn get_render_updates(&self) -> Arc<RwLock<RenderUpdateData>>;
     fn tick_prep(&self, &f32, &World<T>, &Transforms);
     fn tick(&mut self, Arc<RwLock<World<T>>>, Arc<RwLock<Transforms>>, Arc<RwLock<IDManager>>);
-    fn render(&mut self, window: &mut Window) {
+    fn render(&mut self, window: &mut Window) -> Vec<AssetError> {
         self.update_vertices(window);
         self.update_indices(window);
+        self.update_texture(window);
+        let errors = self.update_texture_paths(window);
+        self.update_draw_method(window);
+        errors
     }
     fn get_type(&self) -> T;
     fn get_position(&self) -> Vec3;
+    fn on_spawn(&mut self, _id: BeingId, _world: &World<T>) {}
+    fn on_despawn(&mut self, _world: &World<T>) {}
+    fn runs_while_paused(&self) -> bool {
+        false
+    }
+    fn get_layer(&self) -> i32 {
+        0
+    }
     fn update_vertices(&self, window: &mut Window) {
         let updates = self.get_render_updates();
         let mut updates = updates.write().expect("Unable to Write Render Updates in Update Vertices in Being");
@@ -42,6 +56,18 @@ pub trait Being<T: BeingType> : Send + Sync {
             window.set_texture(self.get_entities().get(&entry.0).expect("Unable to Get Entity in Update Texture in Being"), entry.1);
         }
     }
+    fn update_texture_paths(&self, window: &mut Window) -> Vec<AssetError> {
+        let updates = self.get_render_updates();
+        let mut updates = updates.write().expect("Unable to Write Render Updates in Update Texture Paths in Being");
+        let length = updates.texture_paths.len();
+        let mut errors = vec!();
+        for entry in updates.texture_paths.drain(0..length) {
+            if let Err(err) = window.load_texture(self.get_entities().get(&entry.0).expect("Unable to Get Entity in Update Texture Paths in Being"), &entry.1) {
+                errors.push(err);
+            }
+        }
+        errors
+    }
     fn update_draw_method(&self, window: &mut Window) {
         let updates = self.get_render_updates();
         let mut updates = updates.write().expect("Unable to Write Render Updates in Update Draw Method in Being");
@@ -56,6 +82,7 @@ pub struct RenderUpdateData {
     vertices: Vec<(u64, Vec<Vertex>)>,
     indices: Vec<(u64, Vec<Index>)>,
     texture: Vec<(u64, &'static [u8])>,
+    texture_paths: Vec<(u64, PathBuf)>,
     draw_method: Vec<(u64, DrawMethod)>,
 }
 
@@ -65,7 +92,28 @@ impl RenderUpdateData {
             vertices: vec!(),
             indices: vec!(),
             texture: vec!(),
+            texture_paths: vec!(),
             draw_method: vec!(),
         }
     }
+
+    pub fn push_vertices(&mut self, entity: u64, vertices: Vec<Vertex>) {
+        self.vertices.push((entity, vertices));
+    }
+
+    pub fn push_indices(&mut self, entity: u64, indices: Vec<Index>) {
+        self.indices.push((entity, indices));
+    }
+
+    pub fn push_texture(&mut self, entity: u64, texture: &'static [u8]) {
+        self.texture.push((entity, texture));
+    }
+
+    pub fn push_draw_method(&mut self, entity: u64, draw_method: DrawMethod) {
+        self.draw_method.push((entity, draw_method));
+    }
+
+    pub fn push_texture_path(&mut self, entity: u64, path: PathBuf) {
+        self.texture_paths.push((entity, path));
+    }
 }
@@ -0,0 +1,70 @@
+use std::sync::{Arc, RwLock};
+use time::{precise_time_s};
+
+pub trait Clock: Send + Sync {
+    fn now(&self) -> f64;
+}
+
+pub struct SystemClock;
+
+impl SystemClock {
+    pub fn new() -> SystemClock {
+        SystemClock
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> f64 {
+        precise_time_s()
+    }
+}
+
+#[derive(Clone)]
+pub struct ManualClock {
+    time: Arc<RwLock<f64>>,
+}
+
+impl ManualClock {
+    pub fn new(start: f64) -> ManualClock {
+        ManualClock {
+            time: Arc::new(RwLock::new(start)),
+        }
+    }
+
+    pub fn set_time(&self, time: f64) {
+        *self.time.write().expect("Unable to Write Time in Set Time in Manual Clock") = time;
+    }
+
+    pub fn advance(&self, duration: f64) {
+        *self.time.write().expect("Unable to Write Time in Advance in Manual Clock") += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> f64 {
+        *self.time.read().expect("Unable to Read Time in Now in Manual Clock")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use logic::{Clock, ManualClock};
+
+    #[test]
+    fn manual_clock_only_moves_when_told() {
+        let clock = ManualClock::new(2.0);
+        assert_eq!(clock.now(), 2.0);
+        clock.advance(0.5);
+        assert_eq!(clock.now(), 2.5);
+        clock.set_time(10.0);
+        assert_eq!(clock.now(), 10.0);
+    }
+
+    #[test]
+    fn manual_clock_clones_share_time() {
+        let clock = ManualClock::new(0.0);
+        let shared = clock.clone();
+        clock.advance(1.0);
+        assert_eq!(shared.now(), 1.0);
+    }
+}
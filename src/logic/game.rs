@@ -1,15 +1,19 @@
 use std::sync::{Arc, RwLock};
-use glium::glutin::Event as WindowEvent;
+use std::path::{Path, PathBuf};
+use std::io;
 use glium::glutin::ElementState as GliumElementState;
 use glium::glutin::MouseButton as GliumMouseButton;
 use glium::glutin::VirtualKeyCode as GliumKeyCode;
+use glium::glutin::MouseScrollDelta;
+use glium::glutin::Touch;
+use glium::glutin::ElementState;
 use scoped_threadpool::{Pool};
-use time::{precise_time_s};
 
-use graphics::{IDManager, Transforms, Window};
-use logic::{World, Being, BeingType};
+use graphics::{ID, IDType, IDManager, Transforms, Window, Camera, AssetError, BatchKey};
+use logic::{World, Being, BeingId, BeingType, Clock, SystemClock};
 use math::{Vec2};
-use input::{Keyboard, Mouse, Button, Display};
+use utils::{CursorState, KeyCode, MouseButton, WindowEvent};
+use input::{Keyboard, Mouse, Button, Display, EventLog, InputEvent, TextInput, DroppedFiles, Touches, Recorder, Replay, RecordingError};
 
 pub struct Game<T: BeingType> {
     world: Arc<RwLock<World<T>>>,
@@ -17,8 +21,31 @@ pub struct Game<T: BeingType> {
     display: Arc<RwLock<Display>>,
     mouse: Arc<RwLock<Mouse>>,
     keyboard: Arc<RwLock<Keyboard>>,
+    events: Arc<RwLock<EventLog>>,
+    text: Arc<RwLock<TextInput>>,
+    files: Arc<RwLock<DroppedFiles>>,
+    touches: Arc<RwLock<Touches>>,
     transforms: Arc<RwLock<Transforms>>,
     manager: Arc<RwLock<IDManager>>,
+    released: Vec<(IDType, ID)>,
+    asset_errors: Vec<AssetError>,
+    cameras: Vec<Arc<RwLock<Camera>>>,
+    cursor_state: CursorState,
+    cursor_error: Option<String>,
+    clock: Box<Clock>,
+    tick_rate: f64,
+    max_catch_up: u64,
+    tick_number: u64,
+    delta_time: f64,
+    seed: u64,
+    recorder: Option<Recorder>,
+    recording_error: Option<io::Error>,
+    replay: Option<Replay>,
+    injected: Vec<(u64, WindowEvent)>,
+    last_time: f64,
+    pause_on_focus_loss: bool,
+    focus_paused: bool,
+    paused: bool,
 }
 
 impl<T: BeingType> Game<T> {
@@ -26,114 +53,427 @@ impl<T: BeingType> Game<T> {
         let keyboard = Arc::new(RwLock::new(Keyboard::new()));
         let mouse = Arc::new(RwLock::new(Mouse::new()));
         let display = Arc::new(RwLock::new(Display::new(resolution)));
+        let events = Arc::new(RwLock::new(EventLog::new()));
+        let text = Arc::new(RwLock::new(TextInput::new()));
+        let files = Arc::new(RwLock::new(DroppedFiles::new()));
+        let touches = Arc::new(RwLock::new(Touches::new()));
         Game {
-            world: Arc::new(RwLock::new(World::new(keyboard.clone(), mouse.clone(), display.clone()))),
+            world: Arc::new(RwLock::new(World::new(keyboard.clone(), mouse.clone(), display.clone(), events.clone(), text.clone(), files.clone(), touches.clone()))),
             thread_pool: Pool::new(thread_count),
             display: display,
             mouse: mouse,
             keyboard: keyboard,
+            events: events,
+            text: text,
+            files: files,
+            touches: touches,
             transforms: Arc::new(RwLock::new(Transforms::new())),
             manager: manager,
+            released: vec!(),
+            asset_errors: vec!(),
+            cameras: vec!(),
+            cursor_state: CursorState::Normal,
+            cursor_error: None,
+            clock: Box::new(SystemClock::new()),
+            tick_rate: 60.0,
+            max_catch_up: 10,
+            tick_number: 0,
+            delta_time: 0.0,
+            seed: 0,
+            recorder: None,
+            recording_error: None,
+            replay: None,
+            injected: vec!(),
+            last_time: 0.0,
+            pause_on_focus_loss: true,
+            focus_paused: false,
+            paused: false,
         }
     }
 
+    pub fn add_camera(&mut self, camera: Arc<RwLock<Camera>>) {
+        let resolution = self.display.read().expect("Unable to Read Display in Add Camera in Game").get_resolution();
+        camera.write().expect("Unable to Write Camera in Add Camera in Game").update(&self.transforms.read().expect("Unable to Read Transforms in Add Camera in Game"), resolution);
+        self.cameras.push(camera);
+    }
+
+    pub fn set_clock(&mut self, clock: Box<Clock>) {
+        self.clock = clock;
+    }
+
+    pub fn set_tick_rate(&mut self, tick_rate: f64) -> Result<(), String> {
+        if !(tick_rate > 0.0 && tick_rate.is_finite()) {
+            return Err(format!("Invalid Tick Rate {}", tick_rate));
+        }
+        self.tick_rate = tick_rate;
+        Ok(())
+    }
+
+    pub fn get_tick_rate(&self) -> f64 {
+        self.tick_rate
+    }
+
+    pub fn set_max_catch_up(&mut self, max_catch_up: u64) {
+        self.max_catch_up = max_catch_up;
+    }
+
+    // The tick the next step will run, which is one past World::get_tick_number once a tick has run.
+    pub fn get_tick_number(&self) -> u64 {
+        self.tick_number
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.world.write().expect("Unable to Write World in Set Seed in Game").set_seed(seed);
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn start_recording(&mut self, path: &Path) -> io::Result<()> {
+        self.recording_error = None;
+        self.recorder = Some(Recorder::create(path, self.tick_number, self.tick_rate, self.seed)?);
+        Ok(())
+    }
+
+    pub fn stop_recording(&mut self) -> io::Result<()> {
+        match self.recorder.take() {
+            Some(mut recorder) => recorder.flush(),
+            None => Ok(()),
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    pub fn take_recording_error(&mut self) -> Option<io::Error> {
+        self.recording_error.take()
+    }
+
+    pub fn start_replay(&mut self, path: &Path) -> Result<(), RecordingError> {
+        let mut replay = Replay::load(path)?;
+        replay.set_start_tick(self.tick_number);
+        self.tick_rate = replay.get_tick_rate();
+        let seed = replay.get_seed();
+        self.set_seed(seed);
+        self.delta_time = 0.0;
+        self.replay = Some(replay);
+        Ok(())
+    }
+
+    pub fn stop_replay(&mut self) {
+        self.replay = None;
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        self.replay.is_some()
+    }
+
+    pub fn inject_event(&mut self, tick_number: u64, event: WindowEvent) {
+        self.injected.push((tick_number.max(self.tick_number), event));
+    }
+
+    pub fn press_key(&mut self, tick_number: u64, key_code: KeyCode) {
+        self.inject_event(tick_number, WindowEvent::KeyboardInput(ElementState::Pressed, 0, Some(key_code)));
+    }
+
+    pub fn release_key(&mut self, tick_number: u64, key_code: KeyCode) {
+        self.inject_event(tick_number, WindowEvent::KeyboardInput(ElementState::Released, 0, Some(key_code)));
+    }
+
+    pub fn tap_key(&mut self, tick_number: u64, key_code: KeyCode) {
+        self.press_key(tick_number, key_code);
+        self.release_key(tick_number + 1, key_code);
+    }
+
+    pub fn type_text(&mut self, tick_number: u64, text: &str) {
+        for character in text.chars() {
+            self.inject_event(tick_number, WindowEvent::ReceivedCharacter(character));
+        }
+    }
+
+    pub fn move_mouse(&mut self, tick_number: u64, position: Vec2) {
+        self.inject_event(tick_number, WindowEvent::MouseMoved((position[0] as i32, position[1] as i32)));
+    }
+
+    pub fn press_mouse(&mut self, tick_number: u64, mouse_button: MouseButton) {
+        self.inject_event(tick_number, WindowEvent::MouseInput(ElementState::Pressed, mouse_button));
+    }
+
+    pub fn release_mouse(&mut self, tick_number: u64, mouse_button: MouseButton) {
+        self.inject_event(tick_number, WindowEvent::MouseInput(ElementState::Released, mouse_button));
+    }
+
+    pub fn click_mouse(&mut self, tick_number: u64, mouse_button: MouseButton) {
+        self.press_mouse(tick_number, mouse_button);
+        self.release_mouse(tick_number + 1, mouse_button);
+    }
+
+    pub fn scroll_mouse(&mut self, tick_number: u64, lines: Vec2) {
+        self.inject_event(tick_number, WindowEvent::MouseWheel(MouseScrollDelta::LineDelta(lines[0], lines[1])));
+    }
+
+    pub fn resize(&mut self, tick_number: u64, width: u32, height: u32) {
+        self.inject_event(tick_number, WindowEvent::Resized(width, height));
+    }
+
     pub fn get_world(&self) -> Arc<RwLock<World<T>>> {
         self.world.clone()
     }
 
-    fn pause(&mut self) {
-        println!("Paused");
+    pub fn get_transforms(&self) -> Arc<RwLock<Transforms>> {
+        self.transforms.clone()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.world.read().expect("Unable to Read World in Is Paused in Game").is_paused()
     }
 
-    fn resume(&mut self) {
-        println!("Resumed");
+    pub fn pause(&mut self) {
+        self.world.read().expect("Unable to Read World in Pause in Game").set_paused(true);
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.world.read().expect("Unable to Read World in Resume in Game").set_paused(false);
+        self.paused = false;
+        self.focus_paused = false;
+        self.delta_time = 0.0;
+        self.last_time = self.clock.now();
+    }
+
+    fn sync_paused(&mut self) {
+        let paused = self.is_paused();
+        if paused == self.paused {
+            return;
+        }
+        if paused {
+            self.paused = true;
+        } else {
+            self.resume();
+        }
+    }
+
+    pub fn toggle_pause(&mut self) {
+        if self.is_paused() {
+            self.resume();
+        } else {
+            self.pause();
+        }
+    }
+
+    pub fn set_pause_on_focus_loss(&mut self, pause_on_focus_loss: bool) {
+        self.pause_on_focus_loss = pause_on_focus_loss;
+    }
+
+    fn update_focus(&mut self, focused: bool) {
+        if !self.pause_on_focus_loss || self.replay.is_some() {
+            return;
+        }
+        if focused {
+            if self.focus_paused {
+                self.resume();
+            }
+        } else if !self.is_paused() {
+            self.pause();
+            self.focus_paused = true;
+        }
+    }
+
+    fn handle_window_event(&mut self, event: WindowEvent) -> bool {
+        match event {
+            WindowEvent::Closed => return false,
+            WindowEvent::Focused(focused) => self.update_focus(focused),
+            event => if self.replay.is_none() {
+                self.apply_event(event);
+            },
+        }
+        true
+    }
+
+    fn apply_event(&mut self, event: WindowEvent) {
+        let tick_number = self.tick_number;
+        let recorded = match self.recorder {
+            Some(ref mut recorder) => recorder.record(tick_number, &event),
+            None => Ok(()),
+        };
+        if let Err(err) = recorded {
+            self.recording_error = Some(err);
+            self.recorder = None;
+        }
+        match event {
+            WindowEvent::Resized(width, height) => self.update_resolution((width, height)),
+            WindowEvent::Moved(x, y) => self.update_window_position((x, y)),
+            WindowEvent::DroppedFile(path_buffer) => self.update_dropped_file(path_buffer),
+            WindowEvent::ReceivedCharacter(character) => self.update_text(character),
+            WindowEvent::KeyboardInput(element_state, _, virtual_key_code) => match virtual_key_code {
+                Some(virtual_key_code) => self.update_keyboard(tick_number, virtual_key_code, element_state),
+                None => (),
+            },
+            WindowEvent::MouseMoved(pos) => self.update_mouse_pos(pos),
+            WindowEvent::MouseWheel(mouse_scroll_data) => self.update_mouse_wheel(mouse_scroll_data),
+            WindowEvent::MouseInput(element_state, mouse_button) => self.update_mouse_button(tick_number, mouse_button, element_state),
+            WindowEvent::Refresh => self.request_redraw(),
+            WindowEvent::Touch(touch) => self.update_touch(tick_number, touch),
+            _ => (),
+        }
+    }
+
+    fn apply_replay(&mut self) {
+        let events = match self.replay {
+            Some(ref mut replay) => replay.take_tick(self.tick_number),
+            None => return,
+        };
+        for event in events {
+            self.apply_event(event);
+        }
+        if self.replay.as_ref().map_or(false, |replay| replay.is_finished()) {
+            self.replay = None;
+        }
+    }
+
+    fn apply_injected(&mut self) {
+        let tick_number = self.tick_number;
+        let (due, pending): (Vec<(u64, WindowEvent)>, Vec<(u64, WindowEvent)>) = self.injected.drain(..).partition(|entry| entry.0 <= tick_number);
+        self.injected = pending;
+        for entry in due {
+            self.apply_event(entry.1);
+        }
+    }
+
+    fn push_event(&mut self, event: InputEvent) {
+        self.events.write().expect("Unable to Write Events in Push Event in Game").push(event);
     }
 
     fn update_keyboard(&mut self, tick_number: u64, key_code: GliumKeyCode, element_state: GliumElementState) {
+        self.push_event(InputEvent::Key(key_code, element_state));
         self.keyboard.write().expect("Unable to Write Keyboard in Update Keyboard in Game").set_key_state(key_code, Button::new(tick_number, element_state));
     }
 
     fn update_mouse_button(&mut self, tick_number: u64, mouse_button: GliumMouseButton, element_state: GliumElementState, ) {
+        self.push_event(InputEvent::MouseButton(mouse_button, element_state));
         self.mouse.write().expect("Unable to Write Mouse in Update Mouse Button in Game").set_mouse_button(mouse_button, Button::new(tick_number, element_state));
     }
 
     fn update_mouse_pos(&mut self, mouse_pos: (i32, i32)) {
+        self.push_event(InputEvent::MouseMoved(Vec2::from([mouse_pos.0 as f32, mouse_pos.1 as f32])));
         self.mouse.write().expect("Unable to Write Mouse in Update Mouse Pos in Game").set_mouse_position(Vec2::from([mouse_pos.0 as f32, mouse_pos.1 as f32]));
     }
 
+    fn update_mouse_wheel(&mut self, delta: MouseScrollDelta) {
+        let mut mouse = self.mouse.write().expect("Unable to Write Mouse in Update Mouse Wheel in Game");
+        let before = mouse.get_scroll();
+        match delta {
+            MouseScrollDelta::LineDelta(x, y) => mouse.add_scroll(Vec2::from([x, y])),
+            MouseScrollDelta::PixelDelta(x, y) => mouse.add_pixel_scroll(Vec2::from([x, y])),
+        }
+        let scroll = mouse.get_scroll() - before;
+        drop(mouse);
+        self.push_event(InputEvent::MouseWheel(scroll));
+    }
+
+    fn update_text(&mut self, character: char) {
+        self.push_event(InputEvent::Text(character));
+        self.text.write().expect("Unable to Write Text in Update Text in Game").push_char(character);
+    }
+
+    fn update_dropped_file(&mut self, path: PathBuf) {
+        self.push_event(InputEvent::DroppedFile(path.clone()));
+        self.files.write().expect("Unable to Write Files in Update Dropped File in Game").push(path);
+    }
+
+    fn update_touch(&mut self, tick_number: u64, touch: Touch) {
+        let position = Vec2::from([touch.location.0 as f32, touch.location.1 as f32]);
+        self.push_event(InputEvent::Touch(touch.id, touch.phase, position));
+        self.touches.write().expect("Unable to Write Touches in Update Touch in Game").update(tick_number, touch.id, touch.phase, position);
+    }
+
+    fn update_window_position(&mut self, position: (i32, i32)) {
+        self.display.write().expect("Unable to Write Display in Update Window Position in Game").set_position(Vec2::from([position.0 as f32, position.1 as f32]));
+    }
+
+    fn request_redraw(&mut self) {
+        self.display.write().expect("Unable to Write Display in Request Redraw in Game").request_redraw();
+    }
+
     fn update_resolution(&mut self, resolution: (u32, u32)) {
         self.display.write().expect("Unable to Write Display in Update Resolution in Game").set_resolution(Vec2::from([resolution.0 as f32, resolution.1 as f32]));
     }
 
-    pub fn run(&mut self, window: &mut Window) {
-        let tps: f64 = 60.0;
-        let tps_s: f64 = 1.0 / tps;
+    pub fn step(&mut self, n_ticks: u64) {
+        for _ in 0..n_ticks {
+            self.step_once();
+        }
+    }
 
-        let mut last_time: f64 = precise_time_s();
-        let mut delta_time: f64 = 0.0;
+    pub fn advance(&mut self, duration: f64) -> u64 {
+        let n_ticks = self.accumulate(duration);
+        self.step(n_ticks);
+        n_ticks
+    }
 
-        let mut i: f64 = last_time;
+    fn step_once(&mut self) {
+        self.apply_replay();
+        self.apply_injected();
+        let tick_length = 1.0 / self.tick_rate;
+        self.world.write().expect("Unable to Write World in Step Once in Game").set_tick_number(self.tick_number);
+        self.tick(tick_length as f32);
+        self.sync_paused();
+        self.tick_number += 1;
+        self.events.write().expect("Unable to Write Events in Step Once in Game").begin_tick(self.tick_number);
+        self.mouse.write().expect("Unable to Write Mouse in Step Once in Game").begin_tick();
+        self.world.read().expect("Unable to Read World in Step Once in Game").get_input_stack().write().expect("Unable to Write Contexts in Step Once in Game").begin_tick();
+        self.reclaim_ids();
+        let flushed = match self.recorder {
+            Some(ref mut recorder) => recorder.flush(),
+            None => Ok(()),
+        };
+        if let Err(err) = flushed {
+            self.recording_error = Some(err);
+            self.recorder = None;
+        }
+    }
+
+    fn accumulate(&mut self, duration: f64) -> u64 {
+        self.sync_paused();
+        let tick_length = 1.0 / self.tick_rate;
+        self.delta_time += duration;
+        let mut n_ticks = (self.delta_time / tick_length).floor() as u64;
+        if n_ticks > self.max_catch_up {
+            n_ticks = self.max_catch_up;
+            self.delta_time = 0.0;
+        } else {
+            self.delta_time -= n_ticks as f64 * tick_length;
+        }
+        n_ticks
+    }
+
+    pub fn run(&mut self, window: &mut Window) {
+        self.last_time = self.clock.now();
+
+        let mut i: f64 = self.last_time;
 
         let mut frames: u64 = 0;
         let mut ticks: u64 = 0;
 
-        let mut tick_number: u64 = 0;
-
         loop {
-            let now = precise_time_s();
-            delta_time += now - last_time;
-            last_time = now;
-            while delta_time > 0.0 {
-                for event in window.poll_events(){
-                    match event {
-                        WindowEvent::Resized(width, height) => self.update_resolution((width, height)),
-                        // WindowEvent::Moved(x, y) => {
-                        //
-                        // },
-                        WindowEvent::Closed => return,
-                        // WindowEvent::DroppedFile(path_buffer) => {
-                        //
-                        // },
-                        // WindowEvent::ReceivedCharacter(character) => {
-                        //
-                        // },
-                        WindowEvent::Focused(focused) => {
-                            if focused {
-                                self.resume();
-                            } else {
-                                self.pause();
-                            }
-                        },
-                        WindowEvent::KeyboardInput(element_state, _, virtual_key_code) => match virtual_key_code {
-                            Some(virtual_key_code) => self.update_keyboard(tick_number, virtual_key_code, element_state),
-                            None => (),
-                        },
-                        WindowEvent::MouseMoved(pos) => self.update_mouse_pos(pos),
-                        // WindowEvent::MouseWheel(mouse_scroll_data) => {
-                        //
-                        // },
-                        WindowEvent::MouseInput(element_state, mouse_button) => self.update_mouse_button(tick_number, mouse_button, element_state),
-                        // WindowEvent::Awakened => {
-                        //
-                        // },
-                        // WindowEvent::Refresh => {
-                        //
-                        // },
-                        // WindowEvent::Suspended(suspended) => {
-                        //
-                        // },
-                        // WindowEvent::Touch(touch) => {
-                        //
-                        // },
-                        _ => (),
+            let now = self.clock.now();
+            let elapsed = now - self.last_time;
+            self.last_time = now;
+            let n_ticks = self.accumulate(elapsed);
+            for _ in 0..n_ticks {
+                for event in window.poll_events() {
+                    if !self.handle_window_event(event) {
+                        return;
                     }
                 }
-                self.tick(tps_s as f32);
-                delta_time -= tps_s;
+                self.step_once();
                 ticks += 1;
-                tick_number += 1;
             }
-            self.render(window);
+            let errors = self.render(window);
+            self.asset_errors.extend(errors);
             frames += 1;
             if now > i + 1.0 {
                 i += 1.0;
@@ -144,19 +484,69 @@ impl<T: BeingType> Game<T> {
         }
     }
 
-    fn render(&mut self, window: &mut Window) {
+    fn reclaim_ids(&mut self) {
+        let released = self.manager.write().expect("Unable to Write Manager in Reclaim IDs in Game").take_released();
+        let transforms = self.transforms.read().expect("Unable to Read Transforms in Reclaim IDs in Game");
+        for (id_type, id) in released {
+            match id_type {
+                IDType::Perspective | IDType::View | IDType::Model => transforms.release(id_type, id),
+                _ => self.released.push((id_type, id)),
+            }
+        }
+    }
+
+    pub fn take_cursor_error(&mut self) -> Option<String> {
+        self.cursor_error.take()
+    }
+
+    pub fn take_asset_errors(&mut self) -> Vec<AssetError> {
+        let length = self.asset_errors.len();
+        self.asset_errors.drain(0..length).collect()
+    }
+
+    pub fn render(&mut self, window: &mut Window) -> Vec<AssetError> {
+        self.reclaim_ids();
+        let length = self.released.len();
+        for (id_type, id) in self.released.drain(0..length) {
+            window.release(id_type, id);
+        }
+        let mut errors = window.reload_assets();
+        let cursor_state = self.display.read().expect("Unable to Read Display in Render in Game").get_cursor_state();
+        if cursor_state != self.cursor_state {
+            match window.set_cursor_state(cursor_state) {
+                Ok(()) => self.cursor_state = cursor_state,
+                Err(err) => {
+                    self.display.write().expect("Unable to Write Display in Render in Game").set_cursor_state(self.cursor_state);
+                    self.cursor_error = Some(err);
+                },
+            }
+        }
         let beings = self.world.read().expect("Unable to Read World in Render in Game").get_beings();
         for entry in beings.read().expect("Unable to Read Beings in Render in Game").iter() {
-            entry.1.write().expect("Unable to Write Being in Render in Game").render(window);
+            errors.extend(entry.1.write().expect("Unable to Write Being in Render in Game").render(window));
         }
-        let mut frame = window.frame();
+        let transforms = self.transforms.read().expect("Unable to Read Transforms in Render in Game");
+        let resolution = self.display.read().expect("Unable to Read Display in Render in Game").get_resolution();
+        for camera in self.cameras.iter() {
+            camera.write().expect("Unable to Write Camera in Render in Game").update(&transforms, resolution);
+        }
+        let mut entities = vec!();
         for entry in beings.read().expect("Unable to Read Beings in Render in Game").iter() {
-            let being = entry.1;
-            for entity in being.read().expect("Unable to Read Being in Render in Game").get_entities() {
-                frame.draw_entity(entity.1, &self.transforms);
+            let being = entry.1.read().expect("Unable to Read Being in Render in Game");
+            let layer = being.get_layer();
+            for entity in being.get_entities().values() {
+                let key = BatchKey::new(&entity.read().expect("Unable to Read Entity in Render in Game"));
+                entities.push((layer, key, entity.clone()));
             }
         }
+        entities.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+        let mut frame = window.frame();
+        for entry in entities.iter() {
+            frame.draw(&entry.2.read().expect("Unable to Read Entity in Render in Game"), &transforms);
+        }
         frame.end();
+        self.display.write().expect("Unable to Write Display in Render in Game").clear_redraw_request();
+        errors
     }
 
     fn tick(&mut self, delta_time: f32) {
@@ -164,9 +554,15 @@ impl<T: BeingType> Game<T> {
         let world = &self.world;
         let manager = &self.manager;
         let delta_time = Arc::new(delta_time);
+        let beings = world.read().expect("Unable to Read World in Tick in Game").get_beings();
+        let paused = world.read().expect("Unable to Read World in Tick in Game").is_paused();
+        let mut active: Vec<(BeingId, Arc<RwLock<Box<Being<T>>>>)> = beings.read().expect("Unable to Read Beings in Tick in Game").iter()
+            .filter(|entry| !paused || entry.1.read().expect("Unable to Read Being in Tick in Game").runs_while_paused())
+            .map(|entry| (*entry.0, entry.1.clone()))
+            .collect();
+        active.sort_by_key(|entry| entry.0);
         self.thread_pool.scoped(|scope| {
-            let beings = world.read().expect("Unable to Read World in Tick in Game").get_beings();
-            for entry in beings.read().expect("Unable to Read Beings in Tick in Game").iter() {
+            for entry in active.iter() {
                 let being = entry.1.clone();
                 let world = world.clone();
                 let transforms = transforms.clone();
@@ -175,15 +571,12 @@ impl<T: BeingType> Game<T> {
                     being.read().expect("Unable to Read Being in Tick in Game").tick_prep(&delta_time, &world.read().expect("Unable to Read World in Tick in Game"), &transforms.read().expect("Unable to Read Transforms in Tick in Game"));
                 });
             }
-            for entry in beings.read().expect("Unable to Read Beings in Tick in Game").iter() {
-                let being = entry.1.clone();
-                let world = world.clone();
-                let transforms = transforms.clone();
-                let manager = manager.clone();
-                scope.execute(move || {
-                    being.write().expect("Unable to Write Being in Tick in Game").tick(world, transforms, manager);
-                });
-            }
         });
+        for entry in active.iter() {
+            entry.1.write().expect("Unable to Write Being in Tick in Game").tick(world.clone(), transforms.clone(), manager.clone());
+        }
+        let world = world.read().expect("Unable to Read World in Tick in Game");
+        world.apply_commands();
+        world.update_index();
     }
 }
@@ -0,0 +1,236 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash};
+
+use math::{Vec3};
+use logic::{BeingId};
+
+type Cell = (i32, i32, i32);
+
+const MIN_CELL_SIZE: f32 = 1e-3;
+
+fn clamp_cell_size(cell_size: f32) -> f32 {
+    if cell_size >= MIN_CELL_SIZE {
+        cell_size
+    } else {
+        MIN_CELL_SIZE
+    }
+}
+
+pub struct BeingIndex<T: Eq + Hash + Clone> {
+    cell_size: f32,
+    cells: HashMap<Cell, HashSet<BeingId>>,
+    types: HashMap<T, HashSet<BeingId>>,
+    entries: HashMap<BeingId, (T, Vec3)>,
+}
+
+impl<T: Eq + Hash + Clone> BeingIndex<T> {
+    pub fn new(cell_size: f32) -> BeingIndex<T> {
+        BeingIndex {
+            cell_size: clamp_cell_size(cell_size),
+            cells: HashMap::new(),
+            types: HashMap::new(),
+            entries: HashMap::new(),
+        }
+    }
+
+    pub fn set_cell_size(&mut self, cell_size: f32) {
+        let entries: Vec<(BeingId, (T, Vec3))> = self.entries.drain().collect();
+        self.cell_size = clamp_cell_size(cell_size);
+        self.cells.clear();
+        self.types.clear();
+        for (id, (being_type, position)) in entries {
+            self.update(id, being_type, position);
+        }
+    }
+
+    pub fn get_cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    fn to_cell(&self, position: Vec3) -> Cell {
+        (
+            (position[0] / self.cell_size).floor() as i32,
+            (position[1] / self.cell_size).floor() as i32,
+            (position[2] / self.cell_size).floor() as i32,
+        )
+    }
+
+    pub fn update(&mut self, id: BeingId, being_type: T, position: Vec3) {
+        let new_cell = self.to_cell(position);
+        if let Some(&(ref old_type, old_position)) = self.entries.get(&id) {
+            if *old_type == being_type && self.to_cell(old_position) == new_cell {
+                self.entries.insert(id, (being_type, position));
+                return;
+            }
+        }
+        self.remove(id);
+        self.cells.entry(new_cell).or_insert_with(HashSet::new).insert(id);
+        self.types.entry(being_type.clone()).or_insert_with(HashSet::new).insert(id);
+        self.entries.insert(id, (being_type, position));
+    }
+
+    pub fn remove(&mut self, id: BeingId) {
+        if let Some((being_type, position)) = self.entries.remove(&id) {
+            let cell = self.to_cell(position);
+            let empty_cell = match self.cells.get_mut(&cell) {
+                Some(ids) => {
+                    ids.remove(&id);
+                    ids.is_empty()
+                },
+                None => false,
+            };
+            if empty_cell {
+                self.cells.remove(&cell);
+            }
+            let empty_type = match self.types.get_mut(&being_type) {
+                Some(ids) => {
+                    ids.remove(&id);
+                    ids.is_empty()
+                },
+                None => false,
+            };
+            if empty_type {
+                self.types.remove(&being_type);
+            }
+        }
+    }
+
+    pub fn get_by_type(&self, being_type: &T) -> Vec<BeingId> {
+        match self.types.get(being_type) {
+            Some(ids) => {
+                let mut found: Vec<BeingId> = ids.iter().cloned().collect();
+                found.sort();
+                found
+            },
+            None => vec!(),
+        }
+    }
+
+    pub fn get_in_box(&self, min: Vec3, max: Vec3) -> Vec<BeingId> {
+        if (0..3).any(|i| !(min[i] <= max[i])) {
+            return vec!();
+        }
+        let min_cell = self.to_cell(min);
+        let max_cell = self.to_cell(max);
+        let span = |low: i32, high: i32| (high as i64 - low as i64 + 1) as u64;
+        let volume = span(min_cell.0, max_cell.0).saturating_mul(span(min_cell.1, max_cell.1)).saturating_mul(span(min_cell.2, max_cell.2));
+        let mut found = vec!();
+        if volume > self.cells.len() as u64 {
+            for (cell, ids) in self.cells.iter() {
+                if cell.0 >= min_cell.0 && cell.0 <= max_cell.0 && cell.1 >= min_cell.1 && cell.1 <= max_cell.1 && cell.2 >= min_cell.2 && cell.2 <= max_cell.2 {
+                    self.collect_in_box(ids, min, max, &mut found);
+                }
+            }
+        } else {
+            for x in min_cell.0 as i64..max_cell.0 as i64 + 1 {
+                for y in min_cell.1 as i64..max_cell.1 as i64 + 1 {
+                    for z in min_cell.2 as i64..max_cell.2 as i64 + 1 {
+                        if let Some(ids) = self.cells.get(&(x as i32, y as i32, z as i32)) {
+                            self.collect_in_box(ids, min, max, &mut found);
+                        }
+                    }
+                }
+            }
+        }
+        found.sort();
+        found
+    }
+
+    fn collect_in_box(&self, ids: &HashSet<BeingId>, min: Vec3, max: Vec3, found: &mut Vec<BeingId>) {
+        for id in ids.iter() {
+            let position = self.entries.get(id).expect("Unable to Get Entry in Collect In Box in Being Index").1;
+            if (0..3).all(|i| position[i] >= min[i] && position[i] <= max[i]) {
+                found.push(*id);
+            }
+        }
+    }
+
+    pub fn get_in_radius(&self, center: Vec3, radius: f32) -> Vec<BeingId> {
+        let extent = Vec3::from([radius; 3]);
+        self.get_in_box(center - extent, center + extent).into_iter().filter(|id| {
+            let offset = self.entries.get(id).expect("Unable to Get Entry in Get In Radius in Being Index").1 - center;
+            offset.dot(offset) <= radius * radius
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32;
+
+    use math::{Vec3};
+    use logic::{BeingIndex};
+
+    fn sorted(mut ids: Vec<u64>) -> Vec<u64> {
+        ids.sort();
+        ids
+    }
+
+    fn index() -> BeingIndex<u8> {
+        let mut index = BeingIndex::new(4.0);
+        index.update(0, 0, Vec3::from([1.0, 1.0, 1.0]));
+        index.update(1, 0, Vec3::from([-6.0, 2.0, 3.0]));
+        index.update(2, 1, Vec3::from([9.0, -9.0, 0.5]));
+        index
+    }
+
+    #[test]
+    fn box_query_matches_positions_not_cells() {
+        let index = index();
+        assert_eq!(sorted(index.get_in_box(Vec3::from([0.0, 0.0, 0.0]), Vec3::from([2.0, 2.0, 2.0]))), vec!(0));
+        assert_eq!(sorted(index.get_in_box(Vec3::from([1.5, 1.5, 1.5]), Vec3::from([3.0, 3.0, 3.0]))), Vec::<u64>::new());
+        assert_eq!(sorted(index.get_in_box(Vec3::from([-10.0, -10.0, -10.0]), Vec3::from([10.0, 10.0, 10.0]))), vec!(0, 1, 2));
+    }
+
+    #[test]
+    fn inverted_box_is_empty() {
+        let index = index();
+        assert!(index.get_in_box(Vec3::from([10.0, 10.0, 10.0]), Vec3::from([-10.0, -10.0, -10.0])).is_empty());
+    }
+
+    #[test]
+    fn huge_radius_scans_occupied_cells() {
+        let index = index();
+        assert_eq!(sorted(index.get_in_radius(Vec3::zero(), 1000.0)), vec!(0, 1, 2));
+        assert_eq!(sorted(index.get_in_radius(Vec3::zero(), 5.0)), vec!(0));
+    }
+
+    #[test]
+    fn extreme_boxes_do_not_overflow() {
+        let mut index = index();
+        index.update(3, 0, Vec3::from([f32::MAX, f32::MAX, f32::MAX]));
+        assert_eq!(sorted(index.get_in_box(Vec3::from([f32::MAX; 3]), Vec3::from([f32::MAX; 3]))), vec!(3));
+        assert_eq!(sorted(index.get_in_box(Vec3::from([f32::MIN; 3]), Vec3::from([f32::MAX; 3]))), vec!(0, 1, 2, 3));
+    }
+
+    #[test]
+    fn moving_updates_cells() {
+        let mut index = index();
+        index.update(0, 0, Vec3::from([20.0, 20.0, 20.0]));
+        assert!(index.get_in_box(Vec3::zero(), Vec3::from([2.0, 2.0, 2.0])).is_empty());
+        assert_eq!(index.get_in_radius(Vec3::from([20.0, 20.0, 20.0]), 0.5), vec!(0));
+        index.remove(0);
+        assert!(index.get_by_type(&0).contains(&1));
+        assert!(!index.get_by_type(&0).contains(&0));
+    }
+
+    #[test]
+    fn non_positive_cell_sizes_are_clamped() {
+        for cell_size in [0.0, -1.0, f32::NAN, f32::NEG_INFINITY].iter() {
+            let mut index = index();
+            index.set_cell_size(*cell_size);
+            assert!(index.get_cell_size() > 0.0);
+            assert_eq!(sorted(index.get_in_radius(Vec3::zero(), 5.0)), vec!(0));
+            assert_eq!(sorted(index.get_in_box(Vec3::from([-10.0, -10.0, -10.0]), Vec3::from([10.0, 10.0, 10.0]))), vec!(0, 1, 2));
+            assert!(BeingIndex::<u8>::new(*cell_size).get_cell_size() > 0.0);
+        }
+    }
+
+    #[test]
+    fn infinite_cell_size_keeps_working() {
+        let mut index = index();
+        index.set_cell_size(f32::INFINITY);
+        assert_eq!(sorted(index.get_in_box(Vec3::from([-10.0, -10.0, -10.0]), Vec3::from([10.0, 10.0, 10.0]))), vec!(0, 1, 2));
+        assert_eq!(sorted(index.get_in_radius(Vec3::zero(), 5.0)), vec!(0));
+    }
+}
@@ -1,7 +1,11 @@
 mod game;
 mod being;
 mod world;
+mod clock;
+mod index;
 
 pub use self::game::{Game};
 pub use self::being::{Being, BeingType, RenderUpdateData};
-pub use self::world::{World};
+pub use self::world::{World, BeingId};
+pub use self::clock::{Clock, SystemClock, ManualClock};
+pub use self::index::{BeingIndex};
@@ -1,48 +1,462 @@
 use std::sync::{Arc, RwLock};
 use std::collections::{HashMap};
 
-use input::{Keyboard, Mouse, Display, Button};
-use logic::{Being, BeingType};
-use utils::{KeyCode, MouseButton};
-use math::{Vec2};
+use std::path::{PathBuf};
+use rand::{XorShiftRng, SeedableRng};
+
+use input::{Keyboard, Mouse, Display, Button, ActionMap, EventLog, InputEvent, TextInput, DroppedFiles, Touches, TouchPoint, InputSource, InputStack, InputContext, ContextId};
+use logic::{Being, BeingType, BeingIndex};
+use utils::{KeyCode, MouseButton, ButtonState, CursorState};
+use math::{Vec2, Vec3, Vec4, Ray};
+use graphics::{Camera, Transforms, Entity};
+
+pub type BeingId = u64;
+
+enum WorldCommand<T: BeingType> {
+    Spawn(BeingId, Box<Being<T>>),
+    Despawn(BeingId),
+}
 
 pub struct World<T: BeingType> {
     keyboard: Arc<RwLock<Keyboard>>,
     mouse: Arc<RwLock<Mouse>>,
     display: Arc<RwLock<Display>>,
-    beings: Arc<RwLock<HashMap<u64, Arc<RwLock<Box<Being<T>>>>>>>,
+    events: Arc<RwLock<EventLog>>,
+    text: Arc<RwLock<TextInput>>,
+    files: Arc<RwLock<DroppedFiles>>,
+    touches: Arc<RwLock<Touches>>,
+    actions: Arc<RwLock<ActionMap>>,
+    beings: Arc<RwLock<HashMap<BeingId, Arc<RwLock<Box<Being<T>>>>>>>,
+    next_id: RwLock<BeingId>,
+    commands: RwLock<Vec<WorldCommand<T>>>,
+    index: RwLock<BeingIndex<T>>,
+    tick_number: u64,
+    seed: u64,
+    paused: RwLock<bool>,
+    contexts: RwLock<InputStack>,
 }
 
 impl<T: BeingType> World<T> {
-    pub fn new(keyboard: Arc<RwLock<Keyboard>>, mouse: Arc<RwLock<Mouse>>, display: Arc<RwLock<Display>>) -> World<T> {
+    pub fn new(keyboard: Arc<RwLock<Keyboard>>, mouse: Arc<RwLock<Mouse>>, display: Arc<RwLock<Display>>, events: Arc<RwLock<EventLog>>, text: Arc<RwLock<TextInput>>, files: Arc<RwLock<DroppedFiles>>, touches: Arc<RwLock<Touches>>) -> World<T> {
         World {
             keyboard: keyboard,
             mouse: mouse,
             display: display,
+            events: events,
+            text: text,
+            files: files,
+            touches: touches,
+            actions: Arc::new(RwLock::new(ActionMap::new())),
             beings: Arc::new(RwLock::new(HashMap::new())),
+            next_id: RwLock::new(0),
+            commands: RwLock::new(vec!()),
+            index: RwLock::new(BeingIndex::new(4.0)),
+            tick_number: 0,
+            seed: 0,
+            paused: RwLock::new(false),
+            contexts: RwLock::new(InputStack::new()),
         }
     }
 
-    pub fn get_beings(&self) -> Arc<RwLock<HashMap<u64, Arc<RwLock<Box<Being<T>>>>>>> {
+    pub fn get_beings(&self) -> Arc<RwLock<HashMap<BeingId, Arc<RwLock<Box<Being<T>>>>>>> {
         self.beings.clone()
     }
 
+    pub fn get_being(&self, id: BeingId) -> Option<Arc<RwLock<Box<Being<T>>>>> {
+        self.beings.read().expect("Unable to Read Beings in Get Being in World").get(&id).cloned()
+    }
+
+    fn next_id(&self) -> BeingId {
+        let mut next_id = self.next_id.write().expect("Unable to Write Next ID in Next ID in World");
+        let id = *next_id;
+        *next_id += 1;
+        id
+    }
+
+    pub fn spawn(&self, being: Box<Being<T>>) -> BeingId {
+        let id = self.next_id();
+        self.insert_being(id, being);
+        id
+    }
+
+    pub fn despawn(&self, id: BeingId) {
+        self.remove_being(id);
+    }
+
+    pub fn queue_spawn(&self, being: Box<Being<T>>) -> BeingId {
+        let id = self.next_id();
+        self.commands.write().expect("Unable to Write Commands in Queue Spawn in World").push(WorldCommand::Spawn(id, being));
+        id
+    }
+
+    pub fn queue_despawn(&self, id: BeingId) {
+        self.commands.write().expect("Unable to Write Commands in Queue Despawn in World").push(WorldCommand::Despawn(id));
+    }
+
+    pub fn apply_commands(&self) {
+        let commands: Vec<WorldCommand<T>> = {
+            let mut commands = self.commands.write().expect("Unable to Write Commands in Apply Commands in World");
+            let length = commands.len();
+            commands.drain(0..length).collect()
+        };
+        for command in commands {
+            match command {
+                WorldCommand::Spawn(id, being) => self.insert_being(id, being),
+                WorldCommand::Despawn(id) => self.remove_being(id),
+            }
+        }
+    }
+
+    pub fn update_index(&self) {
+        let beings = self.beings.read().expect("Unable to Read Beings in Update Index in World");
+        let mut index = self.index.write().expect("Unable to Write Index in Update Index in World");
+        for entry in beings.iter() {
+            let being = entry.1.read().expect("Unable to Read Being in Update Index in World");
+            index.update(*entry.0, being.get_type(), being.get_position());
+        }
+    }
+
+    pub fn set_index_cell_size(&self, cell_size: f32) {
+        self.index.write().expect("Unable to Write Index in Set Index Cell Size in World").set_cell_size(cell_size);
+    }
+
+    pub fn get_index_cell_size(&self) -> f32 {
+        self.index.read().expect("Unable to Read Index in Get Index Cell Size in World").get_cell_size()
+    }
+
+    pub fn get_beings_of_type(&self, being_type: &T) -> Vec<BeingId> {
+        self.index.read().expect("Unable to Read Index in Get Beings Of Type in World").get_by_type(being_type)
+    }
+
+    pub fn get_beings_in_box(&self, min: Vec3, max: Vec3) -> Vec<BeingId> {
+        self.index.read().expect("Unable to Read Index in Get Beings In Box in World").get_in_box(min, max)
+    }
+
+    pub fn get_beings_in_radius(&self, center: Vec3, radius: f32) -> Vec<BeingId> {
+        self.index.read().expect("Unable to Read Index in Get Beings In Radius in World").get_in_radius(center, radius)
+    }
+
+    fn insert_being(&self, id: BeingId, mut being: Box<Being<T>>) {
+        being.on_spawn(id, self);
+        self.index.write().expect("Unable to Write Index in Insert Being in World").update(id, being.get_type(), being.get_position());
+        self.beings.write().expect("Unable to Write Beings in Insert Being in World").insert(id, Arc::new(RwLock::new(being)));
+    }
+
+    fn remove_being(&self, id: BeingId) {
+        let being = self.beings.write().expect("Unable to Write Beings in Remove Being in World").remove(&id);
+        self.index.write().expect("Unable to Write Index in Remove Being in World").remove(id);
+        if let Some(being) = being {
+            being.write().expect("Unable to Write Being in Remove Being in World").on_despawn(self);
+        }
+    }
+
     pub fn get_key(&self, key_code: KeyCode) -> Button {
-        self.keyboard.read().expect("Unable to Read Keyboard in Get Key in World").get_key(key_code)
+        self.get_key_in(None, key_code)
+    }
+
+    pub fn get_key_in(&self, context: Option<ContextId>, key_code: KeyCode) -> Button {
+        if self.contexts.read().expect("Unable to Read Contexts in Get Key In in World").is_key_captured(context, key_code) {
+            return Button::new(0, ButtonState::Released);
+        }
+        self.keyboard.read().expect("Unable to Read Keyboard in Get Key In in World").get_key(key_code)
     }
 
     pub fn get_mouse_button(&self, mouse_button: MouseButton) -> Button {
-        self.mouse.read().expect("Unable to Read Mouse in Get Mouse Button in World").get_button(mouse_button)
+        self.get_mouse_button_in(None, mouse_button)
+    }
+
+    pub fn get_mouse_button_in(&self, context: Option<ContextId>, mouse_button: MouseButton) -> Button {
+        let mouse = self.mouse.read().expect("Unable to Read Mouse in Get Mouse Button In in World");
+        if self.contexts.read().expect("Unable to Read Contexts in Get Mouse Button In in World").is_button_captured(context, mouse_button, mouse.get_mouse_position()) {
+            return Button::new(0, ButtonState::Released);
+        }
+        mouse.get_button(mouse_button)
+    }
+
+    pub fn is_mouse_captured(&self, context: Option<ContextId>) -> bool {
+        let position = self.get_mouse_position();
+        self.contexts.read().expect("Unable to Read Contexts in Is Mouse Captured in World").is_mouse_captured(context, position)
+    }
+
+    pub fn get_input_stack(&self) -> &RwLock<InputStack> {
+        &self.contexts
+    }
+
+    pub fn push_context(&self, name: &str) -> ContextId {
+        self.contexts.write().expect("Unable to Write Contexts in Push Context in World").push(name)
+    }
+
+    pub fn remove_context(&self, context: ContextId) {
+        self.contexts.write().expect("Unable to Write Contexts in Remove Context in World").remove(context);
+    }
+
+    pub fn with_context<F: FnOnce(&mut InputContext)>(&self, context: ContextId, f: F) {
+        match self.contexts.write().expect("Unable to Write Contexts in With Context in World").get_context_mut(context) {
+            Some(context) => f(context),
+            None => (),
+        }
+    }
+
+    // The tick being run, or the last tick run when read between steps, unlike Game::get_tick_number.
+    pub fn get_tick_number(&self) -> u64 {
+        self.tick_number
+    }
+
+    pub fn set_tick_number(&mut self, tick_number: u64) {
+        self.tick_number = tick_number;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        *self.paused.read().expect("Unable to Read Paused in Is Paused in World")
+    }
+
+    pub fn set_paused(&self, paused: bool) {
+        *self.paused.write().expect("Unable to Write Paused in Set Paused in World") = paused;
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    pub fn get_rng(&self, salt: u64) -> XorShiftRng {
+        let mut state = self.seed ^ self.tick_number.wrapping_mul(0x9E3779B97F4A7C15) ^ salt.wrapping_mul(0xC2B2AE3D27D4EB4F);
+        let mut words = [0u32; 4];
+        for word in words.iter_mut() {
+            state = state.wrapping_add(0x9E3779B97F4A7C15);
+            let mut mixed = state;
+            mixed = (mixed ^ (mixed >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            mixed = (mixed ^ (mixed >> 27)).wrapping_mul(0x94D049BB133111EB);
+            *word = (mixed ^ (mixed >> 31)) as u32;
+        }
+        words[0] |= 1;
+        XorShiftRng::from_seed(words)
+    }
+
+    pub fn is_key_pressed(&self, key_code: KeyCode) -> bool {
+        self.get_key(key_code).is_pressed()
+    }
+
+    pub fn key_just_pressed(&self, key_code: KeyCode) -> bool {
+        self.get_key(key_code).just_pressed(self.tick_number)
+    }
+
+    pub fn key_just_released(&self, key_code: KeyCode) -> bool {
+        self.get_key(key_code).just_released(self.tick_number)
+    }
+
+    pub fn key_held_for(&self, key_code: KeyCode, ticks: u64) -> bool {
+        self.get_key(key_code).held_for(self.tick_number, ticks)
+    }
+
+    pub fn key_double_tapped(&self, key_code: KeyCode, window: u64) -> bool {
+        self.get_key(key_code).double_pressed(self.tick_number, window)
+    }
+
+    pub fn is_mouse_pressed(&self, mouse_button: MouseButton) -> bool {
+        self.get_mouse_button(mouse_button).is_pressed()
+    }
+
+    pub fn mouse_just_pressed(&self, mouse_button: MouseButton) -> bool {
+        self.get_mouse_button(mouse_button).just_pressed(self.tick_number)
+    }
+
+    pub fn mouse_just_released(&self, mouse_button: MouseButton) -> bool {
+        self.get_mouse_button(mouse_button).just_released(self.tick_number)
+    }
+
+    pub fn mouse_held_for(&self, mouse_button: MouseButton, ticks: u64) -> bool {
+        self.get_mouse_button(mouse_button).held_for(self.tick_number, ticks)
+    }
+
+    pub fn mouse_double_clicked(&self, mouse_button: MouseButton, window: u64) -> bool {
+        self.get_mouse_button(mouse_button).double_pressed(self.tick_number, window)
+    }
+
+    pub fn get_event_log(&self) -> Arc<RwLock<EventLog>> {
+        self.events.clone()
+    }
+
+    pub fn get_input_events(&self) -> Vec<InputEvent> {
+        self.get_input_events_in(None)
+    }
+
+    pub fn get_input_events_in(&self, context: Option<ContextId>) -> Vec<InputEvent> {
+        let position = self.get_mouse_position();
+        let contexts = self.contexts.read().expect("Unable to Read Contexts in Get Input Events In in World");
+        self.events.read().expect("Unable to Read Events in Get Input Events In in World").get_events().iter().filter(|event| !contexts.is_event_captured(context, event, position)).cloned().collect()
+    }
+
+    pub fn count_key_presses(&self, key_code: KeyCode) -> usize {
+        self.count_key_presses_in(None, key_code)
+    }
+
+    pub fn count_key_presses_in(&self, context: Option<ContextId>, key_code: KeyCode) -> usize {
+        if self.contexts.read().expect("Unable to Read Contexts in Count Key Presses In in World").is_key_captured(context, key_code) {
+            return 0;
+        }
+        self.events.read().expect("Unable to Read Events in Count Key Presses In in World").count_key_presses(key_code)
+    }
+
+    pub fn count_mouse_presses(&self, mouse_button: MouseButton) -> usize {
+        self.count_mouse_presses_in(None, mouse_button)
+    }
+
+    pub fn count_mouse_presses_in(&self, context: Option<ContextId>, mouse_button: MouseButton) -> usize {
+        let position = self.get_mouse_position();
+        if self.contexts.read().expect("Unable to Read Contexts in Count Mouse Presses In in World").is_button_captured(context, mouse_button, position) {
+            return 0;
+        }
+        self.events.read().expect("Unable to Read Events in Count Mouse Presses In in World").count_mouse_presses(mouse_button)
+    }
+
+    pub fn get_text_input(&self) -> String {
+        self.get_text_input_in(None)
+    }
+
+    pub fn get_text_input_in(&self, context: Option<ContextId>) -> String {
+        if self.contexts.read().expect("Unable to Read Contexts in Get Text Input In in World").is_text_captured(context) {
+            return String::new();
+        }
+        self.events.read().expect("Unable to Read Events in Get Text Input In in World").get_text()
+    }
+
+    pub fn get_mouse_scroll(&self) -> Vec2 {
+        self.get_mouse_scroll_in(None)
+    }
+
+    pub fn get_mouse_scroll_in(&self, context: Option<ContextId>) -> Vec2 {
+        if self.is_mouse_captured(context) {
+            return Vec2::zero();
+        }
+        self.mouse.read().expect("Unable to Read Mouse in Get Mouse Scroll In in World").get_scroll()
+    }
+
+    pub fn get_text_buffer(&self) -> Arc<RwLock<TextInput>> {
+        self.text.clone()
+    }
+
+    pub fn take_text(&self) -> String {
+        self.text.write().expect("Unable to Write Text in Take Text in World").take_text()
+    }
+
+    pub fn take_dropped_files(&self) -> Vec<PathBuf> {
+        self.files.write().expect("Unable to Write Files in Take Dropped Files in World").take_all()
+    }
+
+    pub fn get_touches(&self) -> Vec<TouchPoint> {
+        self.touches.read().expect("Unable to Read Touches in Get Touches in World").get_touches()
+    }
+
+    pub fn get_touch(&self, id: u64) -> Option<TouchPoint> {
+        self.touches.read().expect("Unable to Read Touches in Get Touch in World").get_touch(id)
+    }
+
+    pub fn get_window_position(&self) -> Vec2 {
+        self.display.read().expect("Unable to Read Display in Get Window Position in World").get_position()
+    }
+
+    pub fn get_actions(&self) -> Arc<RwLock<ActionMap>> {
+        self.actions.clone()
+    }
+
+    pub fn set_actions(&self, actions: ActionMap) {
+        *self.actions.write().expect("Unable to Write Actions in Set Actions in World") = actions;
+    }
+
+    fn lookup(&self, context: Option<ContextId>, source: InputSource) -> Button {
+        match source {
+            InputSource::Key(key_code) => self.get_key_in(context, key_code),
+            InputSource::Mouse(mouse_button) => self.get_mouse_button_in(context, mouse_button),
+        }
+    }
+
+    pub fn is_action_active(&self, name: &str) -> bool {
+        self.is_action_active_in(None, name)
+    }
+
+    pub fn is_action_active_in(&self, context: Option<ContextId>, name: &str) -> bool {
+        self.actions.read().expect("Unable to Read Actions in Is Action Active In in World").is_active(name, &|source| self.lookup(context, source))
+    }
+
+    pub fn action_just_pressed(&self, name: &str) -> bool {
+        self.action_just_pressed_in(None, name)
+    }
+
+    pub fn action_just_pressed_in(&self, context: Option<ContextId>, name: &str) -> bool {
+        self.actions.read().expect("Unable to Read Actions in Action Just Pressed In in World").just_pressed(name, &|source| self.lookup(context, source), self.tick_number)
+    }
+
+    pub fn action_just_released(&self, name: &str) -> bool {
+        self.action_just_released_in(None, name)
+    }
+
+    pub fn action_just_released_in(&self, context: Option<ContextId>, name: &str) -> bool {
+        self.actions.read().expect("Unable to Read Actions in Action Just Released In in World").just_released(name, &|source| self.lookup(context, source), self.tick_number)
+    }
+
+    pub fn get_axis(&self, name: &str) -> f32 {
+        self.get_axis_in(None, name)
+    }
+
+    pub fn get_axis_in(&self, context: Option<ContextId>, name: &str) -> f32 {
+        self.actions.read().expect("Unable to Read Actions in Get Axis In in World").get_axis(name, &|source| self.lookup(context, source))
     }
 
     pub fn get_mouse_position(&self) -> Vec2 {
         self.mouse.read().expect("Unable to Read Mouse in Get Mouse Position in World").get_mouse_position()
     }
 
+    pub fn get_mouse_delta(&self) -> Vec2 {
+        self.mouse.read().expect("Unable to Read Mouse in Get Mouse Delta in World").get_delta()
+    }
+
+    pub fn get_mouse_ndc(&self) -> Vec2 {
+        let resolution = self.get_resolution();
+        self.mouse.read().expect("Unable to Read Mouse in Get Mouse NDC in World").get_ndc(resolution)
+    }
+
+    pub fn get_mouse_world(&self, camera: &Camera, depth: f32) -> Option<Vec3> {
+        camera.ndc_to_world(self.get_mouse_ndc(), depth)
+    }
+
+    pub fn unproject_mouse(&self, transforms: &Transforms, entity: &Entity, depth: f32) -> Option<Vec3> {
+        let ndc = self.get_mouse_ndc();
+        let inverse = match (transforms.get_view_inverse(entity), transforms.get_perspective_inverse(entity)) {
+            (Some(view), Some(perspective)) => view * perspective,
+            _ => return None,
+        };
+        let point = inverse * Vec4::from([ndc[0], ndc[1], depth, 1.0]);
+        Some(Vec3::from(point) * (1.0 / point[3]))
+    }
+
+    pub fn get_cursor_state(&self) -> CursorState {
+        self.display.read().expect("Unable to Read Display in Get Cursor State in World").get_cursor_state()
+    }
+
+    pub fn set_cursor_state(&self, cursor_state: CursorState) {
+        self.display.write().expect("Unable to Write Display in Set Cursor State in World").set_cursor_state(cursor_state);
+    }
+
+    pub fn get_mouse_ray(&self, camera: &Camera) -> Option<Ray> {
+        camera.screen_to_ray(self.get_mouse_position())
+    }
+
+    pub fn pick_plane(&self, camera: &Camera, point: Vec3, normal: Vec3) -> Option<Vec3> {
+        self.get_mouse_ray(camera).and_then(|ray| ray.intersect_plane(point, normal))
+    }
+
     pub fn get_resolution(&self) -> Vec2 {
         self.display.read().expect("Unable to Read Display in Get Resolution in World").get_resolution()
     }
 
+    pub fn is_redraw_requested(&self) -> bool {
+        self.display.read().expect("Unable to Read Display in Is Redraw Requested in World").is_redraw_requested()
+    }
+
     pub fn get_aspect_ratio(&self) -> f32 {
         self.display.read().expect("Unable to Read Display in Get Aspect Ratio in World").get_aspect_ratio()
     }
@@ -0,0 +1,109 @@
+use std::fmt::{Display, Formatter, Error};
+
+use math::{Vec3, Mat4};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Aabb {
+	min: Vec3,
+	max: Vec3,
+}
+
+impl Aabb {
+	pub fn new(min: Vec3, max: Vec3) -> Aabb {
+		Aabb {
+			min: min.min(max),
+			max: min.max(max),
+		}
+	}
+
+	pub fn from_center(center: Vec3, extents: Vec3) -> Aabb {
+		Aabb::new(center - extents, center + extents)
+	}
+
+	pub fn from_points(points: &[Vec3]) -> Option<Aabb> {
+		let first = match points.first() {
+			Some(first) => *first,
+			None => return None,
+		};
+		Some(points.iter().fold(Aabb::new(first, first), |aabb, point| aabb.expand(*point)))
+	}
+
+	pub fn get_min(&self) -> Vec3 {
+		self.min
+	}
+
+	pub fn get_max(&self) -> Vec3 {
+		self.max
+	}
+
+	pub fn get_center(&self) -> Vec3 {
+		(self.min + self.max) * 0.5
+	}
+
+	pub fn get_extents(&self) -> Vec3 {
+		(self.max - self.min) * 0.5
+	}
+
+	pub fn get_size(&self) -> Vec3 {
+		self.max - self.min
+	}
+
+	pub fn get_corners(&self) -> [Vec3; 8] {
+		let mut corners = [Vec3::zero(); 8];
+		for i in 0..8 {
+			corners[i] = Vec3::from([
+				if i & 1 == 0 { self.min[0] } else { self.max[0] },
+				if i & 2 == 0 { self.min[1] } else { self.max[1] },
+				if i & 4 == 0 { self.min[2] } else { self.max[2] },
+			]);
+		}
+		corners
+	}
+
+	pub fn expand(&self, point: Vec3) -> Aabb {
+		Aabb {
+			min: self.min.min(point),
+			max: self.max.max(point),
+		}
+	}
+
+	pub fn union(&self, other: &Aabb) -> Aabb {
+		Aabb {
+			min: self.min.min(other.min),
+			max: self.max.max(other.max),
+		}
+	}
+
+	pub fn closest_point(&self, point: Vec3) -> Vec3 {
+		point.clamp(self.min, self.max)
+	}
+
+	pub fn contains_point(&self, point: Vec3) -> bool {
+		(0..3).all(|i| point[i] >= self.min[i] && point[i] <= self.max[i])
+	}
+
+	pub fn intersects_aabb(&self, other: &Aabb) -> bool {
+		(0..3).all(|i| self.min[i] <= other.max[i] && self.max[i] >= other.min[i])
+	}
+
+	pub fn transform(&self, mat4: &Mat4) -> Aabb {
+		let center = self.get_center();
+		let extents = self.get_extents();
+		let mut new_center = Vec3::zero();
+		let mut new_extents = Vec3::zero();
+		for y in 0..3 {
+			new_center[y] = mat4[y][3];
+			for x in 0..3 {
+				new_center[y] += mat4[y][x] * center[x];
+				new_extents[y] += mat4[y][x].abs() * extents[x];
+			}
+		}
+		Aabb::from_center(new_center, new_extents)
+	}
+}
+
+impl Display for Aabb {
+	fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+		write!(f, "[{} - {}]", self.min, self.max)
+	}
+}
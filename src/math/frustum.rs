@@ -0,0 +1,114 @@
+use std::fmt::{Display, Formatter, Error};
+
+use math::{Vec3, Mat4, Plane, Aabb, Sphere};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Frustum {
+	planes: [Plane; 6],
+}
+
+impl Frustum {
+	pub fn from_matrix(view_projection: &Mat4) -> Frustum {
+		let m = view_projection;
+		Frustum {
+			planes: [
+				Plane::from_vec4(m[3] + m[0]),
+				Plane::from_vec4(m[3] - m[0]),
+				Plane::from_vec4(m[3] + m[1]),
+				Plane::from_vec4(m[3] - m[1]),
+				Plane::from_vec4(m[3] + m[2]),
+				Plane::from_vec4(m[3] - m[2]),
+			],
+		}
+	}
+
+	pub fn get_planes(&self) -> &[Plane; 6] {
+		&self.planes
+	}
+
+	pub fn contains_point(&self, point: Vec3) -> bool {
+		self.planes.iter().all(|plane| plane.signed_distance(point) >= 0.0)
+	}
+
+	pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+		self.planes.iter().all(|plane| plane.signed_distance(sphere.get_center()) >= -sphere.get_radius())
+	}
+
+	pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+		let min = aabb.get_min();
+		let max = aabb.get_max();
+		self.planes.iter().all(|plane| {
+			let normal = plane.get_normal();
+			let positive = Vec3::from([
+				if normal[0] >= 0.0 { max[0] } else { min[0] },
+				if normal[1] >= 0.0 { max[1] } else { min[1] },
+				if normal[2] >= 0.0 { max[2] } else { min[2] },
+			]);
+			plane.signed_distance(positive) >= 0.0
+		})
+	}
+
+	pub fn transform(&self, mat4: &Mat4) -> Option<Frustum> {
+		let inverse_transpose = match mat4.try_inverse() {
+			Some(inverse) => inverse.transpose(),
+			None => return None,
+		};
+		let mut planes = self.planes;
+		for plane in planes.iter_mut() {
+			*plane = Plane::from_vec4(inverse_transpose * plane.to_vec4());
+		}
+		Some(Frustum {
+			planes: planes,
+		})
+	}
+}
+
+impl Display for Frustum {
+	fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+		write!(f, "{}\n{}\n{}\n{}\n{}\n{}", self.planes[0], self.planes[1], self.planes[2], self.planes[3], self.planes[4], self.planes[5])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use math::{Vec3, Mat4, Aabb, Sphere, Frustum};
+
+	fn unit_frustum() -> Frustum {
+		Frustum::from_matrix(&Mat4::identity())
+	}
+
+	#[test]
+	fn intersects_aabb_accepts_boxes_straddling_a_plane() {
+		let frustum = unit_frustum();
+		assert!(frustum.intersects_aabb(&Aabb::new(-Vec3::one() * 0.5, Vec3::one() * 0.5)));
+		assert!(frustum.intersects_aabb(&Aabb::new(Vec3::from([0.5, -0.5, -0.5]), Vec3::from([1.5, 0.5, 0.5]))));
+		assert!(frustum.intersects_aabb(&Aabb::new(Vec3::from([-3.0, -0.5, -0.5]), Vec3::from([-0.5, 0.5, 0.5]))));
+		assert!(frustum.intersects_aabb(&Aabb::new(-Vec3::one() * 5.0, Vec3::one() * 5.0)));
+	}
+
+	#[test]
+	fn intersects_aabb_rejects_boxes_outside_a_plane() {
+		let frustum = unit_frustum();
+		assert!(!frustum.intersects_aabb(&Aabb::new(Vec3::from([1.5, -0.5, -0.5]), Vec3::from([2.5, 0.5, 0.5]))));
+		assert!(!frustum.intersects_aabb(&Aabb::new(Vec3::from([-0.5, -0.5, -3.0]), Vec3::from([0.5, 0.5, -1.5]))));
+	}
+
+	#[test]
+	fn intersects_sphere_and_contains_point() {
+		let frustum = unit_frustum();
+		assert!(frustum.contains_point(Vec3::zero()));
+		assert!(!frustum.contains_point(Vec3::from([0.0, 1.5, 0.0])));
+		assert!(frustum.intersects_sphere(&Sphere::new(Vec3::from([0.0, 1.5, 0.0]), 1.0)));
+		assert!(!frustum.intersects_sphere(&Sphere::new(Vec3::from([0.0, 2.5, 0.0]), 1.0)));
+	}
+
+	#[test]
+	fn transform_moves_the_planes() {
+		let translation = Mat4::translation_from_vec3(Vec3::from([10.0, 0.0, 0.0]));
+		let frustum = unit_frustum().transform(&translation).expect("Unable to Transform Frustum in Test");
+		assert!(frustum.contains_point(Vec3::from([10.5, 0.0, 0.0])));
+		assert!(!frustum.contains_point(Vec3::zero()));
+		let singular = Mat4::scalation_from_vec3(Vec3::from([1.0, 0.0, 1.0]));
+		assert!(unit_frustum().transform(&singular).is_none());
+	}
+}
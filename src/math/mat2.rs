@@ -0,0 +1,211 @@
+use std::ops::{Index, IndexMut, Mul};
+use std::fmt::{Display, Formatter, Error};
+use glium::uniforms::{AsUniformValue, UniformValue};
+use num::{Num, Float, NumCast};
+
+use math::{Vec2, Vector2};
+
+pub type Mat2 = Matrix2<f32>;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Matrix2<T> {
+	vals: [Vector2<T>; 2],
+}
+
+impl<T: Num + Copy> Matrix2<T> {
+	pub fn identity() -> Matrix2<T> {
+		Matrix2 {
+			vals: [Vector2::x_unit(), Vector2::y_unit()],
+		}
+	}
+
+	pub fn zero() -> Matrix2<T> {
+		Matrix2 {
+			vals: [Vector2::zero(); 2],
+		}
+	}
+
+	pub fn transpose(&self) -> Matrix2<T> {
+		Matrix2::from([[
+					self[0][0], 	self[1][0],
+				],[
+					self[0][1], 	self[1][1],
+				]
+			]
+		)
+	}
+
+	pub fn determinant(&self) -> T {
+		self[0][0] * self[1][1] - self[0][1] * self[1][0]
+	}
+
+	pub fn get_vals(&self) -> [[T; 2]; 2] {
+		[
+			self.vals[0].get_vals(),
+			self.vals[1].get_vals(),
+		]
+	}
+
+	pub fn cast<U: Num + Copy + NumCast>(&self) -> Option<Matrix2<U>> where T: NumCast {
+		let mut vals = [Vector2::zero(); 2];
+		for y in 0..2 {
+			vals[y] = match self[y].cast() {
+				Some(row) => row,
+				None => return None,
+			};
+		}
+		Some(Matrix2 {
+			vals: vals,
+		})
+	}
+}
+
+impl<T: Float> Matrix2<T> {
+	pub fn try_inverse(&self) -> Option<Matrix2<T>> {
+		let determinant = self.determinant();
+		let rows = self[0].length() * self[1].length();
+		let columns = Vector2::from([self[0][0], self[1][0]]).length() * Vector2::from([self[0][1], self[1][1]]).length();
+		if determinant.abs() <= T::epsilon() * rows.min(columns) {
+			return None;
+		}
+		let d = T::one() / determinant;
+		Some(Matrix2::from([[
+					self[1][1] * d, 	-self[0][1] * d,
+				],[
+					-self[1][0] * d, 	self[0][0] * d,
+				]
+			]
+		))
+	}
+
+	pub fn to_inverse(&self) -> Matrix2<T> {
+		self.try_inverse().expect("Unable to Invert Singular Matrix in To Inverse in Matrix2")
+	}
+}
+
+impl Mat2 {
+	pub fn rotation(rads: f32) -> Mat2 {
+		let c = rads.cos();
+		let s = rads.sin();
+		Mat2::from([[
+					c, 		-s,
+				],[
+					s, 		c,
+				]
+			]
+		)
+	}
+
+	pub fn scalation_from_vec2(vec2: Vec2) -> Mat2 {
+		Mat2::from([[
+					vec2[0], 	0.0,
+				],[
+					0.0, 		vec2[1],
+				]
+			]
+		)
+	}
+}
+
+impl<T: Copy> From<[[T; 2]; 2]> for Matrix2<T> {
+	fn from(vals: [[T; 2]; 2]) -> Matrix2<T> {
+		Matrix2 {
+			vals: [
+				Vector2::from(vals[0]),
+				Vector2::from(vals[1]),
+			]
+		}
+	}
+}
+
+impl AsUniformValue for Mat2 {
+	fn as_uniform_value(&self) -> UniformValue {
+		UniformValue::Mat2(self.transpose().get_vals())
+	}
+}
+
+impl<T> Index<usize> for Matrix2<T> {
+	type Output = Vector2<T>;
+
+	fn index(&self, index: usize) -> &Vector2<T> {
+		&self.vals[index]
+	}
+}
+
+impl<T> IndexMut<usize> for Matrix2<T> {
+	fn index_mut(&mut self, index: usize) -> &mut Vector2<T> {
+		&mut self.vals[index]
+	}
+}
+
+impl<T: Num + Copy> Mul<Vector2<T>> for Matrix2<T> {
+	type Output = Vector2<T>;
+
+	fn mul(self, other: Vector2<T>) -> Vector2<T> {
+		Vector2::from([self[0].dot(other), self[1].dot(other)])
+	}
+}
+
+impl<T: Num + Copy> Mul<Matrix2<T>> for Matrix2<T> {
+	type Output = Matrix2<T>;
+
+	fn mul(self, other: Matrix2<T>) -> Matrix2<T> {
+		let mut new: Matrix2<T> = Matrix2::zero();
+		for y in 0..2 {
+			for x in 0..2 {
+				let mut sum = T::zero();
+				for i in 0..2 {
+					sum = sum + self[y][i] * other[i][x];
+				}
+				new[y][x] = sum;
+			}
+		}
+		new
+	}
+}
+
+impl<T: Display> Display for Matrix2<T> {
+	fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+		write!(f, "{}\n{}", self[0], self[1])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::f32::consts::{PI};
+
+	use math::{Mat2, Vec2};
+
+	const EPSILON: f32 = 1e-5;
+
+	fn assert_identity(matrix: Mat2) {
+		assert!(matrix[0].approx_eq(Vec2::x_unit(), EPSILON) && matrix[1].approx_eq(Vec2::y_unit(), EPSILON), "{:?}", matrix);
+	}
+
+	#[test]
+	fn inverse_undoes_rotation_and_scale() {
+		let matrix = Mat2::rotation(0.3) * Mat2::scalation_from_vec2(Vec2::from([2.0, -0.5]));
+		assert_identity(matrix * matrix.to_inverse());
+		assert_identity(matrix.to_inverse() * matrix);
+	}
+
+	#[test]
+	fn small_scales_are_invertible() {
+		let matrix = Mat2::scalation_from_vec2(Vec2::from([1e-4, 1e-4]));
+		assert!(matrix.determinant() < f32::EPSILON);
+		assert_identity(matrix * matrix.try_inverse().expect("Unable to Invert in Test"));
+	}
+
+	#[test]
+	fn singular_matrices_have_no_inverse() {
+		assert!(Mat2::zero().try_inverse().is_none());
+		assert!(Mat2::from([[1.0, 2.0], [2.0, 4.0]]).try_inverse().is_none());
+		assert!(Mat2::from([[1e-3, 2e-3], [2e-3, 4e-3 + 1e-12]]).try_inverse().is_none());
+	}
+
+	#[test]
+	fn rotation_turns_counter_clockwise() {
+		assert!((Mat2::rotation(PI / 2.0) * Vec2::x_unit()).approx_eq(Vec2::y_unit(), EPSILON));
+		assert!((Mat2::scalation_from_vec2(Vec2::from([2.0, 3.0])) * Vec2::one()).approx_eq(Vec2::from([2.0, 3.0]), EPSILON));
+	}
+}
@@ -0,0 +1,296 @@
+use std::ops::{Index, IndexMut, Mul};
+use std::fmt::{Display, Formatter, Error};
+use glium::uniforms::{AsUniformValue, UniformValue};
+use num::{Num, Float, NumCast};
+
+use math::{Vec2, Vector2, Vector3, Matrix4};
+
+pub type Mat3 = Matrix3<f32>;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Matrix3<T> {
+	vals: [Vector3<T>; 3],
+}
+
+impl<T: Num + Copy> Matrix3<T> {
+	pub fn identity() -> Matrix3<T> {
+		Matrix3 {
+			vals: [Vector3::x_unit(), Vector3::y_unit(), Vector3::z_unit()],
+		}
+	}
+
+	pub fn zero() -> Matrix3<T> {
+		Matrix3 {
+			vals: [Vector3::zero(); 3],
+		}
+	}
+
+	pub fn transpose(&self) -> Matrix3<T> {
+		let mut new = Matrix3::zero();
+		for y in 0..3 {
+			for x in 0..3 {
+				new[y][x] = self[x][y];
+			}
+		}
+		new
+	}
+
+	pub fn determinant(&self) -> T {
+		self[0][0] * (self[1][1] * self[2][2] - self[1][2] * self[2][1]) -
+		self[0][1] * (self[1][0] * self[2][2] - self[1][2] * self[2][0]) +
+		self[0][2] * (self[1][0] * self[2][1] - self[1][1] * self[2][0])
+	}
+
+	pub fn to_mat4(&self) -> Matrix4<T> {
+		let mut new = Matrix4::identity();
+		for y in 0..3 {
+			for x in 0..3 {
+				new[y][x] = self[y][x];
+			}
+		}
+		new
+	}
+
+	pub fn get_vals(&self) -> [[T; 3]; 3] {
+		[
+			self.vals[0].get_vals(),
+			self.vals[1].get_vals(),
+			self.vals[2].get_vals(),
+		]
+	}
+
+	pub fn cast<U: Num + Copy + NumCast>(&self) -> Option<Matrix3<U>> where T: NumCast {
+		let mut vals = [Vector3::zero(); 3];
+		for y in 0..3 {
+			vals[y] = match self[y].cast() {
+				Some(row) => row,
+				None => return None,
+			};
+		}
+		Some(Matrix3 {
+			vals: vals,
+		})
+	}
+
+	pub fn transform_point(&self, point: Vector2<T>) -> Vector2<T> {
+		Vector2::from(*self * point.to_vec3(T::one()))
+	}
+
+	pub fn transform_vector(&self, vector: Vector2<T>) -> Vector2<T> {
+		Vector2::from(*self * vector.to_vec3(T::zero()))
+	}
+}
+
+impl<T: Float> Matrix3<T> {
+	pub fn try_inverse(&self) -> Option<Matrix3<T>> {
+		let determinant = self.determinant();
+		let m = |y: usize, x: usize| self[y][x];
+		let rows = (0..3).fold(T::one(), |bound, y| bound * Vector3::from([m(y, 0), m(y, 1), m(y, 2)]).length());
+		let columns = (0..3).fold(T::one(), |bound, x| bound * Vector3::from([m(0, x), m(1, x), m(2, x)]).length());
+		if determinant.abs() <= T::epsilon() * rows.min(columns) {
+			return None;
+		}
+		let d = T::one() / determinant;
+		Some(Matrix3::from([[
+					(m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1)) * d, 	(m(0, 2) * m(2, 1) - m(0, 1) * m(2, 2)) * d, 	(m(0, 1) * m(1, 2) - m(0, 2) * m(1, 1)) * d,
+				],[
+					(m(1, 2) * m(2, 0) - m(1, 0) * m(2, 2)) * d, 	(m(0, 0) * m(2, 2) - m(0, 2) * m(2, 0)) * d, 	(m(0, 2) * m(1, 0) - m(0, 0) * m(1, 2)) * d,
+				],[
+					(m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0)) * d, 	(m(0, 1) * m(2, 0) - m(0, 0) * m(2, 1)) * d, 	(m(0, 0) * m(1, 1) - m(0, 1) * m(1, 0)) * d,
+				]
+			]
+		))
+	}
+
+	pub fn to_inverse(&self) -> Matrix3<T> {
+		self.try_inverse().expect("Unable to Invert Singular Matrix in To Inverse in Matrix3")
+	}
+}
+
+impl Mat3 {
+	pub fn translation_2d(vec2: Vec2) -> Mat3 {
+		Mat3::from([[
+					1.0, 	0.0, 	vec2[0],
+				],[
+					0.0, 	1.0, 	vec2[1],
+				],[
+					0.0, 	0.0, 	1.0,
+				]
+			]
+		)
+	}
+
+	pub fn rotation_2d(rads: f32) -> Mat3 {
+		let c = rads.cos();
+		let s = rads.sin();
+		let ns = -s;
+		Mat3::from([[
+					c, 		ns, 	0.0,
+				],[
+					s, 		c, 		0.0,
+				],[
+					0.0, 	0.0, 	1.0,
+				]
+			]
+		)
+	}
+
+	pub fn scalation_2d(vec2: Vec2) -> Mat3 {
+		Mat3::from([[
+					vec2[0], 	0.0, 		0.0,
+				],[
+					0.0, 		vec2[1], 	0.0,
+				],[
+					0.0, 		0.0, 		1.0,
+				]
+			]
+		)
+	}
+
+	pub fn trs_2d(translation: Vec2, rads: f32, scale: Vec2) -> Mat3 {
+		Mat3::translation_2d(translation) * Mat3::rotation_2d(rads) * Mat3::scalation_2d(scale)
+	}
+}
+
+impl<T: Copy> From<[[T; 3]; 3]> for Matrix3<T> {
+	fn from(vals: [[T; 3]; 3]) -> Matrix3<T> {
+		Matrix3 {
+			vals: [
+				Vector3::from(vals[0]),
+				Vector3::from(vals[1]),
+				Vector3::from(vals[2]),
+			]
+		}
+	}
+}
+
+impl<T: Copy> From<Matrix4<T>> for Matrix3<T> {
+	fn from(mat4: Matrix4<T>) -> Matrix3<T> {
+		Matrix3 {
+			vals: [
+				Vector3::from(mat4[0]),
+				Vector3::from(mat4[1]),
+				Vector3::from(mat4[2]),
+			]
+		}
+	}
+}
+
+impl AsUniformValue for Mat3 {
+	fn as_uniform_value(&self) -> UniformValue {
+		UniformValue::Mat3(self.transpose().get_vals())
+	}
+}
+
+impl<T> Index<usize> for Matrix3<T> {
+	type Output = Vector3<T>;
+
+	fn index(&self, index: usize) -> &Vector3<T> {
+		&self.vals[index]
+	}
+}
+
+impl<T> IndexMut<usize> for Matrix3<T> {
+	fn index_mut(&mut self, index: usize) -> &mut Vector3<T> {
+		&mut self.vals[index]
+	}
+}
+
+impl<T: Num + Copy> Mul<Vector3<T>> for Matrix3<T> {
+	type Output = Vector3<T>;
+
+	fn mul(self, other: Vector3<T>) -> Vector3<T> {
+		Vector3::from([self[0].dot(other), self[1].dot(other), self[2].dot(other)])
+	}
+}
+
+impl<T: Num + Copy> Mul<Matrix3<T>> for Matrix3<T> {
+	type Output = Matrix3<T>;
+
+	fn mul(self, other: Matrix3<T>) -> Matrix3<T> {
+		let mut new: Matrix3<T> = Matrix3::zero();
+		for y in 0..3 {
+			for x in 0..3 {
+				let mut sum = T::zero();
+				for i in 0..3 {
+					sum = sum + self[y][i] * other[i][x];
+				}
+				new[y][x] = sum;
+			}
+		}
+		new
+	}
+}
+
+impl<T: Display> Display for Matrix3<T> {
+	fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+		write!(f, "{}\n{}\n{}", self[0], self[1], self[2])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::f32::consts::{PI};
+
+	use math::{Mat3, Mat4, Vec2, Vec3};
+
+	const EPSILON: f32 = 1e-4;
+
+	fn assert_identity(matrix: Mat3) {
+		let identity = Mat3::identity();
+		for y in 0..3 {
+			assert!(matrix[y].approx_eq(identity[y], EPSILON), "{:?}", matrix);
+		}
+	}
+
+	#[test]
+	fn translation_moves_points_but_not_vectors() {
+		let matrix = Mat3::translation_2d(Vec2::from([3.0, -2.0]));
+		assert!(matrix.transform_point(Vec2::one()).approx_eq(Vec2::from([4.0, -1.0]), EPSILON));
+		assert!(matrix.transform_vector(Vec2::one()).approx_eq(Vec2::one(), EPSILON));
+	}
+
+	#[test]
+	fn rotation_and_scale_act_about_the_origin() {
+		assert!(Mat3::rotation_2d(PI / 2.0).transform_point(Vec2::from([2.0, 0.0])).approx_eq(Vec2::from([0.0, 2.0]), EPSILON));
+		assert!(Mat3::scalation_2d(Vec2::from([2.0, -3.0])).transform_point(Vec2::one()).approx_eq(Vec2::from([2.0, -3.0]), EPSILON));
+	}
+
+	#[test]
+	fn trs_scales_then_rotates_then_translates() {
+		let matrix = Mat3::trs_2d(Vec2::from([10.0, 0.0]), PI / 2.0, Vec2::from([2.0, 1.0]));
+		assert!(matrix.transform_point(Vec2::x_unit()).approx_eq(Vec2::from([10.0, 2.0]), EPSILON));
+		assert!(matrix.transform_vector(Vec2::y_unit()).approx_eq(Vec2::from([-1.0, 0.0]), EPSILON));
+	}
+
+	#[test]
+	fn inverse_undoes_trs() {
+		let matrix = Mat3::trs_2d(Vec2::from([5.0, -7.0]), 0.7, Vec2::from([3.0, 0.5]));
+		assert_identity(matrix * matrix.to_inverse());
+		let point = Vec2::from([1.5, 2.5]);
+		assert!(matrix.to_inverse().transform_point(matrix.transform_point(point)).approx_eq(point, EPSILON));
+	}
+
+	#[test]
+	fn small_scales_with_large_translations_are_invertible() {
+		let matrix = Mat3::trs_2d(Vec2::from([1000.0, -1000.0]), 0.2, Vec2::from([0.004, 0.004]));
+		let inverse = matrix.try_inverse().expect("Unable to Invert in Test");
+		let point = Vec2::from([0.25, -0.5]);
+		assert!(inverse.transform_point(matrix.transform_point(point)).approx_eq(point, 1e-2));
+	}
+
+	#[test]
+	fn normal_matrix_survives_small_scales() {
+		let model = Mat4::scalation_from_vec3(Vec3::from([0.004, 0.004, 0.004]));
+		assert!(model.get_upper_left().determinant() < f32::EPSILON);
+		let normal = model.get_normal_matrix().expect("Unable to Get Normal Matrix in Test");
+		assert!(normal[0].approx_eq(Vec3::from([250.0, 0.0, 0.0]), 1e-2));
+	}
+
+	#[test]
+	fn singular_matrices_have_no_inverse() {
+		assert!(Mat3::zero().try_inverse().is_none());
+		assert!(Mat3::scalation_2d(Vec2::from([1.0, 0.0])).try_inverse().is_none());
+		assert!(Mat4::scalation_from_vec3(Vec3::from([1.0, 0.0, 1.0])).get_normal_matrix().is_none());
+	}
+}
@@ -2,26 +2,214 @@ use std::ops::{Index, IndexMut, Mul};
 use std::fmt::{Display, Formatter, Error};
 use std::f32::consts::{PI};
 use glium::uniforms::{AsUniformValue, UniformValue};
+use num::{Num, Float, NumCast};
 
-use math::{Vec3, Vec4};
+use math::{Vec3, Vector3, Vector4, Matrix3, Quat};
+
+pub type Mat4 = Matrix4<f32>;
 
 #[derive(Copy, Clone, PartialEq, Debug)]
-pub struct Mat4 {
-	vals: [Vec4; 4],
+pub struct Matrix4<T> {
+	vals: [Vector4<T>; 4],
 }
 
-impl Mat4 {
-	pub fn identity() -> Mat4 {
-		Mat4 {
-			vals: [Vec4::x_unit(), Vec4::y_unit(), Vec4::z_unit(), Vec4::w_unit()],
+impl<T: Num + Copy> Matrix4<T> {
+	pub fn identity() -> Matrix4<T> {
+		Matrix4 {
+			vals: [Vector4::x_unit(), Vector4::y_unit(), Vector4::z_unit(), Vector4::w_unit()],
+		}
+	}
+
+	pub fn zero() -> Matrix4<T> {
+		Matrix4 {
+			vals: [Vector4::zero(); 4],
+		}
+	}
+
+	pub fn transpose(&self) -> Matrix4<T> {
+		let mut new = Matrix4::zero();
+		for y in 0..4 {
+			for x in 0..4 {
+				new[y][x] = self[x][y];
+			}
+		}
+		new
+	}
+
+	fn swap_rows(&mut self, y1: usize, y2: usize) {
+		let row = self[y1];
+		self[y1] = self[y2];
+		self[y2] = row;
+	}
+
+	fn scale_row(&mut self, y1: usize, scalar: T) {
+		self[y1] = self[y1] * scalar;
+	}
+
+	fn add_row(&mut self, y1: usize, y2: usize, scalar: T) {
+		self[y1] = self[y1] + self[y2] * scalar;
+	}
+
+	pub fn get_vals(&self) -> [[T; 4]; 4] {
+		[
+			self.vals[0].get_vals(),
+			self.vals[1].get_vals(),
+			self.vals[2].get_vals(),
+			self.vals[3].get_vals(),
+		]
+	}
+
+	pub fn get_upper_left(&self) -> Matrix3<T> {
+		Matrix3::from(*self)
+	}
+
+	pub fn determinant(&self) -> T {
+		let m = |y: usize, x: usize| self[y][x];
+		let s0 = m(0, 0) * m(1, 1) - m(1, 0) * m(0, 1);
+		let s1 = m(0, 0) * m(1, 2) - m(1, 0) * m(0, 2);
+		let s2 = m(0, 0) * m(1, 3) - m(1, 0) * m(0, 3);
+		let s3 = m(0, 1) * m(1, 2) - m(1, 1) * m(0, 2);
+		let s4 = m(0, 1) * m(1, 3) - m(1, 1) * m(0, 3);
+		let s5 = m(0, 2) * m(1, 3) - m(1, 2) * m(0, 3);
+		let c5 = m(2, 2) * m(3, 3) - m(3, 2) * m(2, 3);
+		let c4 = m(2, 1) * m(3, 3) - m(3, 1) * m(2, 3);
+		let c3 = m(2, 1) * m(3, 2) - m(3, 1) * m(2, 2);
+		let c2 = m(2, 0) * m(3, 3) - m(3, 0) * m(2, 3);
+		let c1 = m(2, 0) * m(3, 2) - m(3, 0) * m(2, 2);
+		let c0 = m(2, 0) * m(3, 1) - m(3, 0) * m(2, 1);
+		s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+	}
+
+	pub fn cast<U: Num + Copy + NumCast>(&self) -> Option<Matrix4<U>> where T: NumCast {
+		let mut vals = [Vector4::zero(); 4];
+		for y in 0..4 {
+			vals[y] = match self[y].cast() {
+				Some(row) => row,
+				None => return None,
+			};
+		}
+		Some(Matrix4 {
+			vals: vals,
+		})
+	}
+}
+
+impl<T: Float> Matrix4<T> {
+	pub fn try_inverse(&self) -> Option<Matrix4<T>> {
+		let mut me = self.clone();
+		let mut other = Matrix4::identity();
+		let mut scales = [T::zero(); 4];
+		for y in 0..4 {
+			scales[y] = (0..4).fold(T::zero(), |scale, x| scale.max(self[y][x].abs()));
+			if scales[y] == T::zero() {
+				return None;
+			}
+		}
+		for x in 0..4 {
+			let mut pivot = x;
+			for y in x + 1..4 {
+				if me[y][x].abs() / scales[y] > me[pivot][x].abs() / scales[pivot] {
+					pivot = y;
+				}
+			}
+			if me[pivot][x].abs() <= T::epsilon() * scales[pivot] {
+				return None;
+			}
+			me.swap_rows(x, pivot);
+			other.swap_rows(x, pivot);
+			scales.swap(x, pivot);
+			let multiple = T::one() / me[x][x];
+			me.scale_row(x, multiple);
+			other.scale_row(x, multiple);
+			for y in 0..4 {
+				if y != x {
+					let multiple = -me[y][x];
+					me.add_row(y, x, multiple);
+					other.add_row(y, x, multiple);
+				}
+			}
+		}
+		Some(other)
+	}
+
+	pub fn to_inverse(&self) -> Matrix4<T> {
+		self.try_inverse().expect("Unable to Invert Singular Matrix in To Inverse in Matrix4")
+	}
+
+	pub fn is_affine(&self) -> bool {
+		self[3][0] == T::zero() && self[3][1] == T::zero() && self[3][2] == T::zero() && self[3][3] == T::one()
+	}
+
+	pub fn try_affine_inverse(&self) -> Option<Matrix4<T>> {
+		if !self.is_affine() {
+			return None;
 		}
+		let m = |y: usize, x: usize| self[y][x];
+		let c00 = m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1);
+		let c01 = m(1, 2) * m(2, 0) - m(1, 0) * m(2, 2);
+		let c02 = m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0);
+		let determinant = m(0, 0) * c00 + m(0, 1) * c01 + m(0, 2) * c02;
+		let rows = (0..3).fold(T::one(), |bound, y| bound * Vector3::from([m(y, 0), m(y, 1), m(y, 2)]).length());
+		let columns = (0..3).fold(T::one(), |bound, x| bound * Vector3::from([m(0, x), m(1, x), m(2, x)]).length());
+		if determinant.abs() <= T::epsilon() * rows.min(columns) {
+			return None;
+		}
+		let d = T::one() / determinant;
+		let mut new = Matrix4::identity();
+		new[0][0] = c00 * d;
+		new[0][1] = (m(0, 2) * m(2, 1) - m(0, 1) * m(2, 2)) * d;
+		new[0][2] = (m(0, 1) * m(1, 2) - m(0, 2) * m(1, 1)) * d;
+		new[1][0] = c01 * d;
+		new[1][1] = (m(0, 0) * m(2, 2) - m(0, 2) * m(2, 0)) * d;
+		new[1][2] = (m(0, 2) * m(1, 0) - m(0, 0) * m(1, 2)) * d;
+		new[2][0] = c02 * d;
+		new[2][1] = (m(0, 1) * m(2, 0) - m(0, 0) * m(2, 1)) * d;
+		new[2][2] = (m(0, 0) * m(1, 1) - m(0, 1) * m(1, 0)) * d;
+		for y in 0..3 {
+			new[y][3] = -(new[y][0] * m(0, 3) + new[y][1] * m(1, 3) + new[y][2] * m(2, 3));
+		}
+		Some(new)
+	}
+
+	pub fn look_at(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Matrix4<T> {
+		let z = (eye - target).normalize();
+		let x = up.cross(z).normalize();
+		let y = z.cross(x);
+		Matrix4::from([[
+					x[0], 		x[1], 		x[2], 		-x.dot(eye),
+				],[
+					y[0], 		y[1], 		y[2], 		-y.dot(eye),
+				],[
+					z[0], 		z[1], 		z[2], 		-z.dot(eye),
+				],[
+					T::zero(), 	T::zero(), 	T::zero(), 	T::one(),
+				]
+			]
+		)
+	}
+
+	pub fn get_normal_matrix(&self) -> Option<Matrix3<T>> {
+		self.get_upper_left().try_inverse().map(|inverse| inverse.transpose())
+	}
+
+	pub fn get_translation(&self) -> Vector3<T> {
+		Vector3::from([self[0][3], self[1][3], self[2][3]])
 	}
 
-	pub fn zero() -> Mat4 {
-		Mat4{
-			vals: [Vec4::zero(); 4],
+	pub fn get_scale(&self) -> Vector3<T> {
+		let mut scale = Vector3::from([
+			Vector3::from([self[0][0], self[1][0], self[2][0]]).length(),
+			Vector3::from([self[0][1], self[1][1], self[2][1]]).length(),
+			Vector3::from([self[0][2], self[1][2], self[2][2]]).length(),
+		]);
+		if self.determinant() < T::zero() {
+			scale[0] = -scale[0];
 		}
+		scale
 	}
+}
+
+impl Mat4 {
 
 	pub fn perspective(near: f32, far: f32, field_of_view: f32, aspect_ratio: f32) -> Mat4 {
 		let field_of_view = field_of_view * PI / 180.0;
@@ -31,7 +219,7 @@ impl Mat4 {
 				],[
 					0.0, 				d, 		0.0, 							0.0,
 				],[
-					0.0, 				0.0, 	(near + far) / (near - far), 	(2.0 * far) / (near - far),
+					0.0, 				0.0, 	(near + far) / (near - far), 	(2.0 * far * near) / (near - far),
 				],[
 					0.0, 				0.0, 	-1.0, 							0.0,
 				]
@@ -55,6 +243,21 @@ impl Mat4 {
 		)
 	}
 
+	pub fn orthographic_size(near: f32, far: f32, height: f32, aspect_ratio: f32) -> Mat4 {
+		let d = 2.0 / height;
+		Mat4::from([[
+					d / aspect_ratio, 	0.0, 	0.0, 					0.0,
+				],[
+					0.0, 				d, 		0.0, 					0.0,
+				],[
+					0.0, 				0.0, 	-2.0 / (far - near), 	-(far + near) / (far - near),
+				],[
+					0.0, 				0.0, 	0.0, 					1.0,
+				]
+			]
+		)
+	}
+
 	pub fn view_deg(pitch: f32, yaw: f32, camera_position: Vec3) -> Mat4 {
 		Mat4::view(pitch * PI / 180.0, yaw * PI / 180.0, camera_position)
 	}
@@ -115,6 +318,31 @@ impl Mat4 {
 		Mat4::z_rotation(vec3[2]) * Mat4::y_rotation(vec3[1]) * Mat4::x_rotation(vec3[0])
 	}
 
+	pub fn rotation_from_quat(quat: Quat) -> Mat4 {
+		quat.to_mat4()
+	}
+
+	pub fn trs(translation: Vec3, rotation: Quat, scale: Vec3) -> Mat4 {
+		Mat4::translation_from_vec3(translation) * rotation.to_mat4() * Mat4::scalation_from_vec3(scale)
+	}
+
+	pub fn to_quat(&self) -> Quat {
+		Quat::from_mat4(self)
+	}
+
+	pub fn decompose(&self) -> (Vec3, Quat, Vec3) {
+		let translation = self.get_translation();
+		let scale = self.get_scale();
+		let mut rotation = Mat4::identity();
+		for x in 0..3 {
+			let factor = if scale[x] == 0.0 { 0.0 } else { 1.0 / scale[x] };
+			for y in 0..3 {
+				rotation[y][x] = self[y][x] * factor;
+			}
+		}
+		(translation, Quat::from_mat4(&rotation), scale)
+	}
+
 	pub fn x_rotation(x_rads: f32) -> Mat4 {
 		let c = x_rads.cos();
 		let s = x_rads.sin();
@@ -165,71 +393,16 @@ impl Mat4 {
 			]
 		)
 	}
-
-	pub fn to_inverse(&self) -> Mat4 {
-		let mut me = self.clone();
-		let mut other = Mat4::identity();
-		let mut real_y = 0;
-		for x in 0..4 {
-			for y in real_y..4 {
-				if me[y][x] != 0.0 {
-					me.swap_rows(y, real_y);
-					other.swap_rows(y, real_y);
-					let multiple = 1.0 / me[real_y][x];
-					me.scale_row(real_y, multiple);
-					other.scale_row(real_y, multiple);
-					for y2 in real_y + 1..4 {
-						let multiple = -me[y2][x];
-						me.add_row(y2, real_y, multiple);
-						other.add_row(y2, real_y, multiple);
-					}
-					real_y += 1;
-					break;
-				}
-			}
-		}
-		for x in 1..4 {
-			for y in 0..x {
-				let multiple = -me[y][x];
-				me.add_row(y, x, multiple);
-				other.add_row(y, x, multiple);
-			}
-		}
-		other
-	}
-
-	fn swap_rows(&mut self, y1: usize, y2: usize) {
-		let row = self[y1];
-		self[y1] = self[y2];
-		self[y2] = row;
-	}
-
-	fn scale_row(&mut self, y1: usize, scalar: f32) {
-		self[y1] = self[y1] * scalar;
-	}
-
-	fn add_row(&mut self, y1: usize, y2: usize, scalar: f32) {
-		self[y1] = self[y1] + self[y2] * scalar;
-	}
-
-	fn get_vals(&self) -> [[f32; 4]; 4] {
-		[
-			self.vals[0].get_vals(),
-			self.vals[1].get_vals(),
-			self.vals[2].get_vals(),
-			self.vals[3].get_vals(),
-		]
-	}
 }
 
-impl From<[[f32; 4]; 4]> for Mat4 {
-	fn from(vals: [[f32; 4]; 4]) -> Mat4 {
-		Mat4 {
+impl<T: Copy> From<[[T; 4]; 4]> for Matrix4<T> {
+	fn from(vals: [[T; 4]; 4]) -> Matrix4<T> {
+		Matrix4 {
 			vals: [
-				Vec4::from(vals[0]),
-				Vec4::from(vals[1]),
-				Vec4::from(vals[2]),
-				Vec4::from(vals[3]),
+				Vector4::from(vals[0]),
+				Vector4::from(vals[1]),
+				Vector4::from(vals[2]),
+				Vector4::from(vals[3]),
 			]
 		}
 	}
@@ -237,60 +410,115 @@ impl From<[[f32; 4]; 4]> for Mat4 {
 
 impl AsUniformValue for Mat4 {
 	fn as_uniform_value(&self) -> UniformValue {
-		UniformValue::Mat4(self.get_vals())
+		UniformValue::Mat4(self.transpose().get_vals())
 	}
 }
 
-impl Index<usize> for Mat4 {
-	type Output = Vec4;
+impl<T> Index<usize> for Matrix4<T> {
+	type Output = Vector4<T>;
 
-	fn index(&self, index: usize) -> &Vec4 {
+	fn index(&self, index: usize) -> &Vector4<T> {
 		&self.vals[index]
 	}
 }
 
-impl IndexMut<usize> for Mat4 {
-	fn index_mut(&mut self, index: usize) -> &mut Vec4 {
+impl<T> IndexMut<usize> for Matrix4<T> {
+	fn index_mut(&mut self, index: usize) -> &mut Vector4<T> {
 		&mut self.vals[index]
 	}
 }
 
-impl Mul<Vec4> for Mat4 {
-	type Output = Vec4;
+impl<T: Num + Copy> Mul<Vector4<T>> for Matrix4<T> {
+	type Output = Vector4<T>;
 
-	fn mul(self, other: Vec4) -> Vec4 {
-		let mut new: Vec4 = Vec4::zero();
+	fn mul(self, other: Vector4<T>) -> Vector4<T> {
+		let mut new: Vector4<T> = Vector4::zero();
 		for y in 0..4 {
-			let mut dot = 0.0;
-			for x in 0..4 {
-				dot += self[y][x] * other[x];
-			}
-			new[y] = dot;
+			new[y] = self[y].dot(other);
 		}
 		new
 	}
 }
 
-impl Mul<Mat4> for Mat4 {
-	type Output = Mat4;
+impl<T: Num + Copy> Mul<Matrix4<T>> for Matrix4<T> {
+	type Output = Matrix4<T>;
 
-	fn mul(self, other: Mat4) -> Mat4 {
-		let mut new: Mat4 = Mat4::zero();
-		for x in 0..4 {
-			for y in 0..4 {
-				let mut sum = 0.0;
+	fn mul(self, other: Matrix4<T>) -> Matrix4<T> {
+		let mut new: Matrix4<T> = Matrix4::zero();
+		for y in 0..4 {
+			for x in 0..4 {
+				let mut sum = T::zero();
 				for i in 0..4 {
-					sum += self[i][x] * other[y][i];
+					sum = sum + self[y][i] * other[i][x];
 				}
-				new[x][y] = sum;
+				new[y][x] = sum;
 			}
 		}
 		new
 	}
 }
 
-impl Display for Mat4 {
+impl<T: Display> Display for Matrix4<T> {
 	fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
 		write!(f, "{}\n{}\n{}\n{}", self[0], self[1], self[2], self[3])
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use math::{Mat4, Vec3, Quat};
+
+	const EPSILON: f32 = 1e-4;
+
+	fn assert_identity(matrix: Mat4) {
+		let identity = Mat4::identity();
+		for y in 0..4 {
+			assert!(matrix[y].approx_eq(identity[y], EPSILON), "{:?}", matrix);
+		}
+	}
+
+	#[test]
+	fn small_uniform_scales_are_invertible() {
+		let matrix = Mat4::trs(Vec3::from([3.0, -2.0, 1.0]), Quat::from_axis_angle(Vec3::y_unit(), 0.5), Vec3::from([1e-3, 1e-3, 1e-3]));
+		assert!((matrix.get_upper_left().determinant() - 1e-9).abs() < 1e-12);
+		let affine = matrix.try_affine_inverse().expect("Unable to Invert Affine in Test");
+		let general = matrix.try_inverse().expect("Unable to Invert in Test");
+		assert_identity(matrix * affine);
+		assert_identity(matrix * general);
+	}
+
+	#[test]
+	fn badly_scaled_rows_are_invertible() {
+		let matrix = Mat4::scalation_from_vec3(Vec3::from([1e-8, 1.0, 1e4]));
+		let inverse = matrix.try_inverse().expect("Unable to Invert in Test");
+		assert_identity(matrix * inverse);
+		assert!(matrix.try_affine_inverse().is_some());
+	}
+
+	#[test]
+	fn singular_matrices_have_no_inverse() {
+		let flat = Mat4::scalation_from_vec3(Vec3::from([1.0, 0.0, 1.0]));
+		assert!(flat.try_inverse().is_none());
+		assert!(flat.try_affine_inverse().is_none());
+		let mut dependent = Mat4::identity();
+		dependent[1] = dependent[0] * 3.0;
+		assert!(dependent.try_inverse().is_none());
+		assert!(dependent.try_affine_inverse().is_none());
+		let nearly = Mat4::from([
+			[1e-3, 1e-3, 0.0, 0.0],
+			[0.0, 0.0, 1e-3, 0.0],
+			[1e-3, 1e-3 + 1e-12, 0.0, 0.0],
+			[0.0, 0.0, 0.0, 1.0],
+		]);
+		assert!(nearly.try_affine_inverse().is_none());
+		assert!(nearly.try_inverse().is_none());
+		assert!(Mat4::zero().try_inverse().is_none());
+	}
+
+	#[test]
+	fn perspective_round_trips() {
+		let perspective = Mat4::perspective(0.1, 100.0, 1.0, 4.0 / 3.0);
+		assert!(perspective.try_affine_inverse().is_none());
+		assert_identity(perspective * perspective.try_inverse().expect("Unable to Invert Perspective in Test"));
+	}
+}
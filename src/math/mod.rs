@@ -1,9 +1,27 @@
 mod vec2;
 mod vec3;
 mod vec4;
+mod mat2;
+mod mat3;
 mod mat4;
+mod ray;
+mod plane;
+mod aabb;
+mod sphere;
+mod frustum;
+mod rect;
+mod quat;
 
-pub use self::vec2::Vec2;
-pub use self::vec3::Vec3;
-pub use self::vec4::Vec4;
-pub use self::mat4::Mat4;
+pub use self::vec2::{Vec2, Vector2};
+pub use self::vec3::{Vec3, Vector3};
+pub use self::vec4::{Vec4, Vector4};
+pub use self::mat2::{Mat2, Matrix2};
+pub use self::mat3::{Mat3, Matrix3};
+pub use self::mat4::{Mat4, Matrix4};
+pub use self::ray::Ray;
+pub use self::plane::Plane;
+pub use self::aabb::Aabb;
+pub use self::sphere::Sphere;
+pub use self::frustum::Frustum;
+pub use self::rect::Rect;
+pub use self::quat::Quat;
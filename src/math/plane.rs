@@ -0,0 +1,117 @@
+use std::fmt::{Display, Formatter, Error};
+
+use math::{Vec3, Vec4, Mat4, Ray};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Plane {
+	normal: Vec3,
+	distance: f32,
+}
+
+impl Plane {
+	pub fn new(normal: Vec3, distance: f32) -> Plane {
+		let length = normal.length();
+		if length == 0.0 {
+			return Plane {
+				normal: normal,
+				distance: distance,
+			};
+		}
+		Plane {
+			normal: normal / length,
+			distance: distance / length,
+		}
+	}
+
+	pub fn from_point_normal(point: Vec3, normal: Vec3) -> Plane {
+		let normal = normal.normalize();
+		Plane::new(normal, normal.dot(point))
+	}
+
+	pub fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Plane {
+		Plane::from_point_normal(a, (b - a).cross(c - a))
+	}
+
+	pub fn from_vec4(vec4: Vec4) -> Plane {
+		Plane::new(Vec3::from(vec4), -vec4[3])
+	}
+
+	pub fn get_normal(&self) -> Vec3 {
+		self.normal
+	}
+
+	pub fn get_distance(&self) -> f32 {
+		self.distance
+	}
+
+	pub fn to_vec4(&self) -> Vec4 {
+		self.normal.to_vec4(-self.distance)
+	}
+
+	pub fn signed_distance(&self, point: Vec3) -> f32 {
+		self.normal.dot(point) - self.distance
+	}
+
+	pub fn project_point(&self, point: Vec3) -> Vec3 {
+		point - self.normal * self.signed_distance(point)
+	}
+
+	pub fn intersect_ray(&self, ray: &Ray) -> Option<f32> {
+		let denominator = self.normal.dot(ray.get_direction());
+		if denominator.abs() < 1e-6 {
+			return None;
+		}
+		let t = (self.distance - self.normal.dot(ray.get_origin())) / denominator;
+		if t < 0.0 {
+			return None;
+		}
+		Some(t)
+	}
+
+	pub fn transform(&self, mat4: &Mat4) -> Option<Plane> {
+		mat4.try_inverse().map(|inverse| Plane::from_vec4(inverse.transpose() * self.to_vec4()))
+	}
+}
+
+impl Display for Plane {
+	fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+		write!(f, "{} . p = {}", self.normal, self.distance)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use math::{Vec3, Mat4, Plane, Ray};
+
+	const EPSILON: f32 = 1e-5;
+
+	#[test]
+	fn intersect_ray_returns_the_distance_along_the_ray() {
+		let plane = Plane::from_point_normal(Vec3::from([0.0, 0.0, 4.0]), -Vec3::z_unit());
+		let ray = Ray::new(Vec3::from([1.0, 2.0, 0.0]), Vec3::from([0.0, 0.0, 2.0]));
+		let t = plane.intersect_ray(&ray).expect("Ray must Hit Plane in Test");
+		assert!((t - 2.0).abs() < EPSILON);
+		assert!(ray.at(t).approx_eq(Vec3::from([1.0, 2.0, 4.0]), EPSILON));
+		let behind = Ray::new(Vec3::from([1.0, 2.0, 0.0]), -Vec3::z_unit());
+		assert!(plane.intersect_ray(&behind).is_none());
+		let parallel = Ray::new(Vec3::from([1.0, 2.0, 0.0]), Vec3::x_unit());
+		assert!(plane.intersect_ray(&parallel).is_none());
+	}
+
+	#[test]
+	fn transform_moves_the_plane() {
+		let plane = Plane::from_point_normal(Vec3::zero(), Vec3::y_unit());
+		let translation = Mat4::translation_from_vec3(Vec3::from([0.0, 3.0, 0.0]));
+		let moved = plane.transform(&translation).expect("Unable to Transform Plane in Test");
+		assert!(moved.get_normal().approx_eq(Vec3::y_unit(), EPSILON));
+		assert!((moved.get_distance() - 3.0).abs() < EPSILON);
+		assert!(moved.signed_distance(Vec3::from([5.0, 3.0, -2.0])).abs() < EPSILON);
+	}
+
+	#[test]
+	fn transform_by_a_singular_matrix_returns_none() {
+		let plane = Plane::from_point_normal(Vec3::zero(), Vec3::y_unit());
+		let flatten = Mat4::scalation_from_vec3(Vec3::from([1.0, 0.0, 1.0]));
+		assert!(plane.transform(&flatten).is_none());
+	}
+}
@@ -0,0 +1,338 @@
+use std::ops::{Index, IndexMut, Mul};
+use std::fmt::{Display, Formatter, Error};
+use std::f32::consts::{PI};
+
+use math::{Vec3, Mat4};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Quat {
+	vals: [f32; 4],
+}
+
+impl Quat {
+	pub fn identity() -> Quat {
+		Quat::from([0.0, 0.0, 0.0, 1.0])
+	}
+
+	pub fn from_axis_angle(axis: Vec3, radians: f32) -> Quat {
+		let length = axis.dot(axis).sqrt();
+		if length == 0.0 {
+			return Quat::identity();
+		}
+		let s = (radians / 2.0).sin() / length;
+		Quat::from([axis[0] * s, axis[1] * s, axis[2] * s, (radians / 2.0).cos()])
+	}
+
+	pub fn from_axis_angle_deg(axis: Vec3, degrees: f32) -> Quat {
+		Quat::from_axis_angle(axis, degrees * PI / 180.0)
+	}
+
+	pub fn from_euler(vec3: Vec3) -> Quat {
+		Quat::from_axis_angle(Vec3::from([0.0, 0.0, 1.0]), vec3[2]) *
+		Quat::from_axis_angle(Vec3::from([0.0, 1.0, 0.0]), vec3[1]) *
+		Quat::from_axis_angle(Vec3::from([1.0, 0.0, 0.0]), vec3[0])
+	}
+
+	pub fn from_euler_deg(vec3: Vec3) -> Quat {
+		Quat::from_euler(vec3 * (PI / 180.0))
+	}
+
+	pub fn from_mat4(mat4: &Mat4) -> Quat {
+		let trace = mat4[0][0] + mat4[1][1] + mat4[2][2];
+		let quat = if trace > 0.0 {
+			let s = (trace + 1.0).sqrt() * 2.0;
+			Quat::from([(mat4[2][1] - mat4[1][2]) / s, (mat4[0][2] - mat4[2][0]) / s, (mat4[1][0] - mat4[0][1]) / s, 0.25 * s])
+		} else if mat4[0][0] > mat4[1][1] && mat4[0][0] > mat4[2][2] {
+			let s = (1.0 + mat4[0][0] - mat4[1][1] - mat4[2][2]).sqrt() * 2.0;
+			Quat::from([0.25 * s, (mat4[0][1] + mat4[1][0]) / s, (mat4[0][2] + mat4[2][0]) / s, (mat4[2][1] - mat4[1][2]) / s])
+		} else if mat4[1][1] > mat4[2][2] {
+			let s = (1.0 + mat4[1][1] - mat4[0][0] - mat4[2][2]).sqrt() * 2.0;
+			Quat::from([(mat4[0][1] + mat4[1][0]) / s, 0.25 * s, (mat4[1][2] + mat4[2][1]) / s, (mat4[0][2] - mat4[2][0]) / s])
+		} else {
+			let s = (1.0 + mat4[2][2] - mat4[0][0] - mat4[1][1]).sqrt() * 2.0;
+			Quat::from([(mat4[0][2] + mat4[2][0]) / s, (mat4[1][2] + mat4[2][1]) / s, 0.25 * s, (mat4[1][0] - mat4[0][1]) / s])
+		};
+		quat.normalize()
+	}
+
+	pub fn get_vals(&self) -> [f32; 4] {
+		self.vals
+	}
+
+	pub fn get_vector(&self) -> Vec3 {
+		Vec3::from([self[0], self[1], self[2]])
+	}
+
+	pub fn get_scalar(&self) -> f32 {
+		self[3]
+	}
+
+	pub fn dot(&self, other: Quat) -> f32 {
+		let mut sum = 0.0;
+
+		for i in 0..4 {
+			sum += self[i] * other[i];
+		}
+
+		sum
+	}
+
+	pub fn length(&self) -> f32 {
+		self.dot(*self).sqrt()
+	}
+
+	pub fn normalize(&self) -> Quat {
+		let length = self.length();
+		if length == 0.0 {
+			return Quat::identity();
+		}
+		self.scale(1.0 / length)
+	}
+
+	pub fn conjugate(&self) -> Quat {
+		Quat::from([-self[0], -self[1], -self[2], self[3]])
+	}
+
+	pub fn inverse(&self) -> Quat {
+		let length_squared = self.dot(*self);
+		if length_squared == 0.0 {
+			return Quat::identity();
+		}
+		self.conjugate().scale(1.0 / length_squared)
+	}
+
+	pub fn get_angle(&self) -> f32 {
+		2.0 * self[3].max(-1.0).min(1.0).acos()
+	}
+
+	pub fn get_axis(&self) -> Vec3 {
+		let s = (1.0 - self[3] * self[3]).max(0.0).sqrt();
+		if s < 0.0001 {
+			return Vec3::from([1.0, 0.0, 0.0]);
+		}
+		self.get_vector() * (1.0 / s)
+	}
+
+	pub fn rotate(&self, vec3: Vec3) -> Vec3 {
+		let u = self.get_vector();
+		let w = self[3];
+		let uv = u.cross(vec3);
+		let uuv = u.cross(uv);
+		vec3 + (uv * w + uuv) * 2.0
+	}
+
+	pub fn nlerp(&self, other: Quat, t: f32) -> Quat {
+		let other = if self.dot(other) < 0.0 { other.scale(-1.0) } else { other };
+		let mut new = Quat::identity();
+		for i in 0..4 {
+			new[i] = self[i] + (other[i] - self[i]) * t;
+		}
+		new.normalize()
+	}
+
+	pub fn slerp(&self, other: Quat, t: f32) -> Quat {
+		let mut cos = self.dot(other);
+		let other = if cos < 0.0 {
+			cos = -cos;
+			other.scale(-1.0)
+		} else {
+			other
+		};
+		if cos > 0.9995 {
+			return self.nlerp(other, t);
+		}
+		let angle = cos.acos();
+		let sin = angle.sin();
+		let a = ((1.0 - t) * angle).sin() / sin;
+		let b = (t * angle).sin() / sin;
+		let mut new = Quat::identity();
+		for i in 0..4 {
+			new[i] = self[i] * a + other[i] * b;
+		}
+		new
+	}
+
+	pub fn to_mat4(&self) -> Mat4 {
+		let x = self[0];
+		let y = self[1];
+		let z = self[2];
+		let w = self[3];
+		Mat4::from([[
+					1.0 - 2.0 * (y * y + z * z), 	2.0 * (x * y - z * w), 			2.0 * (x * z + y * w), 			0.0,
+				],[
+					2.0 * (x * y + z * w), 			1.0 - 2.0 * (x * x + z * z), 	2.0 * (y * z - x * w), 			0.0,
+				],[
+					2.0 * (x * z - y * w), 			2.0 * (y * z + x * w), 			1.0 - 2.0 * (x * x + y * y), 	0.0,
+				],[
+					0.0, 							0.0, 							0.0, 							1.0,
+				]
+			]
+		)
+	}
+
+	fn scale(&self, scalar: f32) -> Quat {
+		Quat::from([self[0] * scalar, self[1] * scalar, self[2] * scalar, self[3] * scalar])
+	}
+}
+
+impl From<[f32; 4]> for Quat {
+	fn from(vals: [f32; 4]) -> Quat {
+		Quat {
+			vals: vals,
+		}
+	}
+}
+
+impl Index<usize> for Quat {
+	type Output = f32;
+
+	fn index(&self, index: usize) -> &f32 {
+		&self.vals[index]
+	}
+}
+
+impl IndexMut<usize> for Quat {
+	fn index_mut(&mut self, index: usize) -> &mut f32 {
+		&mut self.vals[index]
+	}
+}
+
+impl Mul<Quat> for Quat {
+	type Output = Quat;
+
+	fn mul(self, other: Quat) -> Quat {
+		Quat::from([
+			self[3] * other[0] + self[0] * other[3] + self[1] * other[2] - self[2] * other[1],
+			self[3] * other[1] - self[0] * other[2] + self[1] * other[3] + self[2] * other[0],
+			self[3] * other[2] + self[0] * other[1] - self[1] * other[0] + self[2] * other[3],
+			self[3] * other[3] - self[0] * other[0] - self[1] * other[1] - self[2] * other[2],
+		])
+	}
+}
+
+impl Mul<Vec3> for Quat {
+	type Output = Vec3;
+
+	fn mul(self, other: Vec3) -> Vec3 {
+		self.rotate(other)
+	}
+}
+
+impl Display for Quat {
+	fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+		write!(f, "({}, {}, {}, {})", self[0], self[1], self[2], self[3])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use math::{Quat, Mat4, Vec3};
+
+	const EPSILON: f32 = 1e-4;
+
+	fn assert_mat4_eq(a: Mat4, b: Mat4) {
+		for y in 0..4 {
+			assert!(a[y].approx_eq(b[y], EPSILON), "{:?} != {:?}", a, b);
+		}
+	}
+
+	fn assert_same_rotation(a: Quat, b: Quat) {
+		assert!(a.dot(b).abs() > 1.0 - EPSILON, "{} != {}", a, b);
+	}
+
+	#[test]
+	fn axis_angle_matches_the_mat4_rotations() {
+		for angle in [0.5, -1.25, 3.0].iter() {
+			assert_mat4_eq(Quat::from_axis_angle(Vec3::x_unit(), *angle).to_mat4(), Mat4::x_rotation(*angle));
+			assert_mat4_eq(Quat::from_axis_angle(Vec3::y_unit(), *angle).to_mat4(), Mat4::y_rotation(*angle));
+			assert_mat4_eq(Quat::from_axis_angle(Vec3::z_unit() * 4.0, *angle).to_mat4(), Mat4::z_rotation(*angle));
+		}
+		assert_mat4_eq(Quat::from_axis_angle_deg(Vec3::z_unit(), 90.0).to_mat4(), Mat4::z_rotation(::std::f32::consts::PI / 2.0));
+		assert_eq!(Quat::from_axis_angle(Vec3::zero(), 1.0), Quat::identity());
+	}
+
+	#[test]
+	fn euler_matches_rotation_from_vec3() {
+		let angles = Vec3::from([0.3, -0.7, 1.1]);
+		assert_mat4_eq(Quat::from_euler(angles).to_mat4(), Mat4::rotation_from_vec3(angles));
+		assert_mat4_eq(Quat::from_euler_deg(Vec3::from([90.0, 0.0, 0.0])).to_mat4(), Mat4::x_rotation(::std::f32::consts::PI / 2.0));
+	}
+
+	#[test]
+	fn from_mat4_round_trips_through_every_branch() {
+		let quats = [
+			Quat::from_axis_angle(Vec3::from([1.0, 2.0, -1.0]), 0.5),
+			Quat::from_axis_angle(Vec3::x_unit(), 3.0),
+			Quat::from_axis_angle(Vec3::y_unit(), 3.0),
+			Quat::from_axis_angle(Vec3::z_unit(), 3.0),
+			Quat::from_axis_angle(Vec3::from([1.0, 1.0, 0.0]), ::std::f32::consts::PI),
+		];
+		for quat in quats.iter() {
+			let matrix = quat.to_mat4();
+			let round_trip = Quat::from_mat4(&matrix);
+			assert_same_rotation(round_trip, *quat);
+			assert!((round_trip.length() - 1.0).abs() < EPSILON);
+			assert_mat4_eq(round_trip.to_mat4(), matrix);
+		}
+	}
+
+	#[test]
+	fn rotate_matches_the_matrix() {
+		let quat = Quat::from_euler(Vec3::from([0.4, 1.2, -2.0]));
+		let matrix = quat.to_mat4();
+		for point in [Vec3::x_unit(), Vec3::from([1.0, -2.0, 3.0]), Vec3::zero()].iter() {
+			let expected = Vec3::from(matrix * point.to_vec4(1.0));
+			assert!(quat.rotate(*point).approx_eq(expected, EPSILON));
+			assert!((quat * *point).approx_eq(expected, EPSILON));
+		}
+		let product = Quat::from_axis_angle(Vec3::z_unit(), 0.5) * Quat::from_axis_angle(Vec3::x_unit(), 1.0);
+		assert_mat4_eq(product.to_mat4(), Mat4::z_rotation(0.5) * Mat4::x_rotation(1.0));
+		assert_same_rotation(quat * quat.inverse(), Quat::identity());
+	}
+
+	#[test]
+	fn slerp_and_nlerp_hit_the_endpoints_and_midpoint() {
+		let a = Quat::from_axis_angle(Vec3::z_unit(), 0.2);
+		let b = Quat::from_axis_angle(Vec3::z_unit(), 1.4);
+		let middle = Quat::from_axis_angle(Vec3::z_unit(), 0.8);
+		assert_same_rotation(a.slerp(b, 0.0), a);
+		assert_same_rotation(a.slerp(b, 1.0), b);
+		assert_same_rotation(a.slerp(b, 0.5), middle);
+		assert_same_rotation(a.nlerp(b, 0.0), a);
+		assert_same_rotation(a.nlerp(b, 1.0), b);
+		assert_same_rotation(a.nlerp(b, 0.5), middle);
+		assert!((a.slerp(b, 0.25).get_angle() - 0.5).abs() < EPSILON);
+	}
+
+	#[test]
+	fn slerp_handles_nearly_identical_and_opposite_quaternions() {
+		let a = Quat::from_axis_angle(Vec3::y_unit(), 1.0);
+		let nearly = Quat::from_axis_angle(Vec3::y_unit(), 1.0 + 1e-4);
+		let halfway = a.slerp(nearly, 0.5);
+		assert!(halfway.get_vals().iter().all(|val| val.is_finite()));
+		assert_same_rotation(halfway, Quat::from_axis_angle(Vec3::y_unit(), 1.0 + 0.5e-4));
+
+		let negated = Quat::from([-a[0], -a[1], -a[2], -a[3]]);
+		assert_same_rotation(a.slerp(negated, 0.5), a);
+		assert_same_rotation(a.nlerp(negated, 0.5), a);
+
+		let half_turn = Quat::from_axis_angle(Vec3::z_unit(), ::std::f32::consts::PI);
+		let quarter_turn = Quat::identity().slerp(half_turn, 0.5);
+		assert!((quarter_turn.get_angle() - ::std::f32::consts::PI / 2.0).abs() < EPSILON);
+		assert!((quarter_turn.rotate(Vec3::x_unit())[1].abs() - 1.0).abs() < EPSILON);
+	}
+
+	#[test]
+	fn trs_decomposes_into_its_parts() {
+		let translation = Vec3::from([1.0, -2.0, 3.0]);
+		let rotation = Quat::from_euler(Vec3::from([0.3, -0.7, 1.1]));
+		let scale = Vec3::from([2.0, 0.5, 3.0]);
+		let matrix = Mat4::trs(translation, rotation, scale);
+		let parts = matrix.decompose();
+		assert!(parts.0.approx_eq(translation, EPSILON));
+		assert_same_rotation(parts.1, rotation);
+		assert!(parts.2.approx_eq(scale, EPSILON));
+		assert_mat4_eq(Mat4::trs(parts.0, parts.1, parts.2), matrix);
+		assert_same_rotation(Mat4::trs(translation, rotation, Vec3::one()).to_quat(), rotation);
+	}
+}
@@ -0,0 +1,217 @@
+use std::fmt::{Display, Formatter, Error};
+
+use math::{Vec3, Mat4, Aabb, Sphere, Plane};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Ray {
+	origin: Vec3,
+	direction: Vec3,
+}
+
+impl Ray {
+	pub fn new(origin: Vec3, direction: Vec3) -> Ray {
+		Ray {
+			origin: origin,
+			direction: direction,
+		}
+	}
+
+	pub fn get_origin(&self) -> Vec3 {
+		self.origin
+	}
+
+	pub fn get_direction(&self) -> Vec3 {
+		self.direction
+	}
+
+	pub fn at(&self, t: f32) -> Vec3 {
+		self.origin + self.direction * t
+	}
+
+	pub fn intersect_plane(&self, point: Vec3, normal: Vec3) -> Option<Vec3> {
+		Plane::from_point_normal(point, normal).intersect_ray(self).map(|t| self.at(t))
+	}
+
+	pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<f32> {
+		let min = aabb.get_min();
+		let max = aabb.get_max();
+		let mut near = 0.0f32;
+		let mut far = ::std::f32::INFINITY;
+		for i in 0..3 {
+			if self.direction[i].abs() < 1e-6 {
+				if self.origin[i] < min[i] || self.origin[i] > max[i] {
+					return None;
+				}
+				continue;
+			}
+			let inverse = 1.0 / self.direction[i];
+			let mut t0 = (min[i] - self.origin[i]) * inverse;
+			let mut t1 = (max[i] - self.origin[i]) * inverse;
+			if t0 > t1 {
+				let temp = t0;
+				t0 = t1;
+				t1 = temp;
+			}
+			near = near.max(t0);
+			far = far.min(t1);
+			if near > far {
+				return None;
+			}
+		}
+		Some(near)
+	}
+
+	pub fn intersect_sphere(&self, sphere: &Sphere) -> Option<f32> {
+		let offset = self.origin - sphere.get_center();
+		let a = self.direction.length_squared();
+		if a == 0.0 {
+			return None;
+		}
+		let b = offset.dot(self.direction);
+		let c = offset.length_squared() - sphere.get_radius() * sphere.get_radius();
+		let discriminant = b * b - a * c;
+		if discriminant < 0.0 {
+			return None;
+		}
+		let root = discriminant.sqrt();
+		let near = (-b - root) / a;
+		if near >= 0.0 {
+			return Some(near);
+		}
+		let far = (-b + root) / a;
+		if far >= 0.0 {
+			return Some(0.0);
+		}
+		None
+	}
+
+	pub fn intersect_triangle(&self, a: Vec3, b: Vec3, c: Vec3) -> Option<f32> {
+		let edge1 = b - a;
+		let edge2 = c - a;
+		let p = self.direction.cross(edge2);
+		let determinant = edge1.dot(p);
+		if determinant.abs() < 1e-6 {
+			return None;
+		}
+		let inverse = 1.0 / determinant;
+		let offset = self.origin - a;
+		let u = offset.dot(p) * inverse;
+		if u < 0.0 || u > 1.0 {
+			return None;
+		}
+		let q = offset.cross(edge1);
+		let v = self.direction.dot(q) * inverse;
+		if v < 0.0 || u + v > 1.0 {
+			return None;
+		}
+		let t = edge2.dot(q) * inverse;
+		if t < 0.0 {
+			return None;
+		}
+		Some(t)
+	}
+
+	pub fn transform(&self, mat4: &Mat4) -> Ray {
+		Ray {
+			origin: Vec3::from(*mat4 * self.origin.to_vec4(1.0)),
+			direction: Vec3::from(*mat4 * self.direction.to_vec4(0.0)),
+		}
+	}
+}
+
+impl Display for Ray {
+	fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+		write!(f, "{} -> {}", self.origin, self.direction)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use math::{Vec3, Aabb, Sphere, Ray};
+
+	const EPSILON: f32 = 1e-5;
+
+	fn unit_box() -> Aabb {
+		Aabb::new(-Vec3::one(), Vec3::one())
+	}
+
+	#[test]
+	fn intersect_plane_returns_the_hit_point() {
+		let ray = Ray::new(Vec3::from([1.0, 4.0, -2.0]), Vec3::from([0.0, -2.0, 0.0]));
+		let hit = ray.intersect_plane(Vec3::from([7.0, 1.0, 3.0]), Vec3::from([0.0, 5.0, 0.0])).expect("Ray must Hit Plane in Test");
+		assert!(hit.approx_eq(Vec3::from([1.0, 1.0, -2.0]), EPSILON));
+		assert!(ray.intersect_plane(Vec3::from([0.0, 5.0, 0.0]), Vec3::y_unit()).is_none());
+		assert!(ray.intersect_plane(Vec3::zero(), Vec3::x_unit()).is_none());
+		assert!(ray.intersect_plane(Vec3::zero(), Vec3::zero()).is_none());
+	}
+
+	#[test]
+	fn intersect_aabb_hits_the_near_face() {
+		let ray = Ray::new(Vec3::from([-5.0, 0.5, 0.5]), Vec3::x_unit());
+		let t = ray.intersect_aabb(&unit_box()).expect("Ray must Hit Box in Test");
+		assert!((t - 4.0).abs() < EPSILON);
+		let ray = Ray::new(Vec3::from([-5.0, 0.5, 0.5]), -Vec3::x_unit());
+		assert!(ray.intersect_aabb(&unit_box()).is_none());
+	}
+
+	#[test]
+	fn intersect_aabb_handles_rays_parallel_to_a_slab() {
+		let inside = Ray::new(Vec3::from([-5.0, 0.5, -0.5]), Vec3::x_unit());
+		assert!(inside.intersect_aabb(&unit_box()).is_some());
+		let on_face = Ray::new(Vec3::from([-5.0, 1.0, 0.0]), Vec3::x_unit());
+		assert!(on_face.intersect_aabb(&unit_box()).is_some());
+		let outside = Ray::new(Vec3::from([-5.0, 1.5, 0.0]), Vec3::x_unit());
+		assert!(outside.intersect_aabb(&unit_box()).is_none());
+		let diagonal = Ray::new(Vec3::from([-5.0, -5.0, 2.0]), Vec3::from([1.0, 1.0, 0.0]));
+		assert!(diagonal.intersect_aabb(&unit_box()).is_none());
+	}
+
+	#[test]
+	fn intersect_aabb_from_inside_returns_zero() {
+		let ray = Ray::new(Vec3::from([0.25, -0.5, 0.0]), Vec3::from([1.0, 2.0, -1.0]).normalize());
+		assert_eq!(ray.intersect_aabb(&unit_box()), Some(0.0));
+		let parallel = Ray::new(Vec3::zero(), Vec3::z_unit());
+		assert_eq!(parallel.intersect_aabb(&unit_box()), Some(0.0));
+	}
+
+	#[test]
+	fn intersect_sphere_returns_the_nearest_distance() {
+		let sphere = Sphere::new(Vec3::from([0.0, 0.0, 10.0]), 2.0);
+		let ray = Ray::new(Vec3::zero(), Vec3::z_unit());
+		let t = ray.intersect_sphere(&sphere).expect("Ray must Hit Sphere in Test");
+		assert!((t - 8.0).abs() < EPSILON);
+		let tangent = Ray::new(Vec3::from([2.0, 0.0, 0.0]), Vec3::z_unit());
+		assert!(tangent.intersect_sphere(&sphere).is_some());
+		let miss = Ray::new(Vec3::from([2.5, 0.0, 0.0]), Vec3::z_unit());
+		assert!(miss.intersect_sphere(&sphere).is_none());
+		let behind = Ray::new(Vec3::zero(), -Vec3::z_unit());
+		assert!(behind.intersect_sphere(&sphere).is_none());
+	}
+
+	#[test]
+	fn intersect_sphere_from_inside_returns_zero() {
+		let sphere = Sphere::new(Vec3::zero(), 2.0);
+		let ray = Ray::new(Vec3::from([0.5, 0.0, 0.0]), -Vec3::x_unit());
+		assert_eq!(ray.intersect_sphere(&sphere), Some(0.0));
+		let degenerate = Ray::new(Vec3::from([5.0, 0.0, 0.0]), Vec3::zero());
+		assert!(degenerate.intersect_sphere(&sphere).is_none());
+	}
+
+	#[test]
+	fn intersect_triangle_respects_edges_and_direction() {
+		let a = Vec3::zero();
+		let b = Vec3::x_unit();
+		let c = Vec3::y_unit();
+		let ray = Ray::new(Vec3::from([0.25, 0.25, 3.0]), -Vec3::z_unit());
+		let t = ray.intersect_triangle(a, b, c).expect("Ray must Hit Triangle in Test");
+		assert!((t - 3.0).abs() < EPSILON);
+		let from_below = Ray::new(Vec3::from([0.25, 0.25, -3.0]), Vec3::z_unit());
+		assert!(from_below.intersect_triangle(a, b, c).is_some());
+		let outside = Ray::new(Vec3::from([0.75, 0.75, 3.0]), -Vec3::z_unit());
+		assert!(outside.intersect_triangle(a, b, c).is_none());
+		let behind = Ray::new(Vec3::from([0.25, 0.25, 3.0]), Vec3::z_unit());
+		assert!(behind.intersect_triangle(a, b, c).is_none());
+		let parallel = Ray::new(Vec3::from([-1.0, 0.25, 0.0]), Vec3::x_unit());
+		assert!(parallel.intersect_triangle(a, b, c).is_none());
+	}
+}
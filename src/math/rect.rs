@@ -0,0 +1,80 @@
+use std::fmt::{Display, Formatter, Error};
+
+use math::{Vec2, Mat3};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Rect {
+	min: Vec2,
+	max: Vec2,
+}
+
+impl Rect {
+	pub fn new(min: Vec2, max: Vec2) -> Rect {
+		Rect {
+			min: min.min(max),
+			max: min.max(max),
+		}
+	}
+
+	pub fn from_position_size(position: Vec2, size: Vec2) -> Rect {
+		Rect::new(position, position + size)
+	}
+
+	pub fn get_min(&self) -> Vec2 {
+		self.min
+	}
+
+	pub fn get_max(&self) -> Vec2 {
+		self.max
+	}
+
+	pub fn get_center(&self) -> Vec2 {
+		(self.min + self.max) * 0.5
+	}
+
+	pub fn get_size(&self) -> Vec2 {
+		self.max - self.min
+	}
+
+	pub fn get_corners(&self) -> [Vec2; 4] {
+		[
+			self.min,
+			Vec2::from([self.max[0], self.min[1]]),
+			self.max,
+			Vec2::from([self.min[0], self.max[1]]),
+		]
+	}
+
+	pub fn contains_point(&self, point: Vec2) -> bool {
+		point[0] >= self.min[0] && point[0] <= self.max[0] && point[1] >= self.min[1] && point[1] <= self.max[1]
+	}
+
+	pub fn intersects(&self, other: &Rect) -> bool {
+		self.min[0] <= other.max[0] && self.max[0] >= other.min[0] && self.min[1] <= other.max[1] && self.max[1] >= other.min[1]
+	}
+
+	pub fn union(&self, other: &Rect) -> Rect {
+		Rect {
+			min: self.min.min(other.min),
+			max: self.max.max(other.max),
+		}
+	}
+
+	pub fn transform(&self, mat3: &Mat3) -> Rect {
+		let corners = self.get_corners();
+		let first = mat3.transform_point(corners[0]);
+		let mut rect = Rect::new(first, first);
+		for corner in corners.iter().skip(1) {
+			let point = mat3.transform_point(*corner);
+			rect.min = rect.min.min(point);
+			rect.max = rect.max.max(point);
+		}
+		rect
+	}
+}
+
+impl Display for Rect {
+	fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+		write!(f, "[{} - {}]", self.min, self.max)
+	}
+}
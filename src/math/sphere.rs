@@ -0,0 +1,76 @@
+use std::fmt::{Display, Formatter, Error};
+
+use math::{Vec3, Vec4, Mat4, Aabb};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Sphere {
+	center: Vec3,
+	radius: f32,
+}
+
+impl Sphere {
+	pub fn new(center: Vec3, radius: f32) -> Sphere {
+		Sphere {
+			center: center,
+			radius: radius.abs(),
+		}
+	}
+
+	pub fn get_center(&self) -> Vec3 {
+		self.center
+	}
+
+	pub fn get_radius(&self) -> f32 {
+		self.radius
+	}
+
+	pub fn contains_point(&self, point: Vec3) -> bool {
+		self.center.distance_squared(point) <= self.radius * self.radius
+	}
+
+	pub fn intersects_sphere(&self, other: &Sphere) -> bool {
+		let radius = self.radius + other.radius;
+		self.center.distance_squared(other.center) <= radius * radius
+	}
+
+	pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+		self.contains_point(aabb.closest_point(self.center))
+	}
+
+	pub fn get_aabb(&self) -> Aabb {
+		Aabb::from_center(self.center, Vec3::one() * self.radius)
+	}
+
+	pub fn transform(&self, mat4: &Mat4) -> Sphere {
+		let center = Vec3::from(*mat4 * self.center.to_vec4(1.0));
+		let scale = mat4.get_scale().abs();
+		Sphere::new(center, self.radius * scale[0].max(scale[1]).max(scale[2]))
+	}
+}
+
+impl From<Sphere> for Vec4 {
+	fn from(sphere: Sphere) -> Vec4 {
+		sphere.center.to_vec4(sphere.radius)
+	}
+}
+
+impl Display for Sphere {
+	fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+		write!(f, "({}, r {})", self.center, self.radius)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use math::{Vec3, Aabb, Sphere};
+
+	#[test]
+	fn intersects_aabb_uses_the_closest_point() {
+		let aabb = Aabb::new(-Vec3::one(), Vec3::one());
+		assert!(Sphere::new(Vec3::zero(), 0.1).intersects_aabb(&aabb));
+		assert!(Sphere::new(Vec3::from([3.0, 0.0, 0.0]), 2.0).intersects_aabb(&aabb));
+		assert!(!Sphere::new(Vec3::from([3.5, 0.0, 0.0]), 2.0).intersects_aabb(&aabb));
+		assert!(Sphere::new(Vec3::from([2.0, 2.0, 2.0]), 1.8).intersects_aabb(&aabb));
+		assert!(!Sphere::new(Vec3::from([2.0, 2.0, 2.0]), 1.6).intersects_aabb(&aabb));
+	}
+}
@@ -1,63 +1,163 @@
 use glium::uniforms::{AsUniformValue, UniformValue};
-use std::ops::{Index, IndexMut, Add, Sub, Mul};
+use num::{Num, Float, NumCast};
+use std::ops::{Index, IndexMut, Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
 use std::fmt::{Display, Formatter, Error};
 
-use math::{Vec3, Vec4};
+use math::{Vector3, Vector4};
+
+pub type Vec2 = Vector2<f32>;
 
 #[derive(Copy, Clone, PartialEq, Debug)]
-pub struct Vec2 {
-	vals: [f32; 2],
+pub struct Vector2<T> {
+	vals: [T; 2],
 }
 
-impl Vec2 {
-	pub fn zero() -> Vec2 {
-		Vec2::from([0.0; 2])
+impl<T: Num + Copy> Vector2<T> {
+	pub fn zero() -> Vector2<T> {
+		Vector2::from([T::zero(); 2])
+	}
+
+	pub fn one() -> Vector2<T> {
+		Vector2::from([T::one(); 2])
 	}
 
-	pub fn one() -> Vec2 {
-		Vec2::from([1.0; 2])
+	pub fn x_unit() -> Vector2<T> {
+		Vector2::from([T::one(), T::zero()])
 	}
 
-	pub fn get_vals(&self) -> [f32; 2] {
+	pub fn y_unit() -> Vector2<T> {
+		Vector2::from([T::zero(), T::one()])
+	}
+
+	pub fn get_vals(&self) -> [T; 2] {
 		self.vals
 	}
 
-	pub fn dot(&self, other: Vec2) -> f32 {
-		let mut sum = 0.0;
+	pub fn dot(&self, other: Vector2<T>) -> T {
+		let mut sum = T::zero();
 
 		for i in 0..2 {
-			sum += self[i] * other[i];
+			sum = sum + self[i] * other[i];
 		}
 
 		sum
 	}
 
-	pub fn to_vec3(&self, z: f32) -> Vec3 {
-		Vec3::from([self[0], self[1], z])
+	pub fn to_vec3(&self, z: T) -> Vector3<T> {
+		Vector3::from([self[0], self[1], z])
+	}
+
+	pub fn to_vec4(&self, z: T, w: T) -> Vector4<T> {
+		Vector4::from([self[0], self[1], z, w])
 	}
 
-	pub fn to_vec4(&self, z: f32, w: f32) -> Vec4 {
-		Vec4::from([self[0], self[1], z, w])
+	pub fn cross(&self, other: Vector2<T>) -> T {
+		self[0] * other[1] - self[1] * other[0]
+	}
+
+	pub fn length_squared(&self) -> T {
+		self.dot(*self)
+	}
+
+	pub fn distance_squared(&self, other: Vector2<T>) -> T {
+		(other - *self).length_squared()
+	}
+
+	pub fn cast<U: Num + Copy + NumCast>(&self) -> Option<Vector2<U>> where T: NumCast {
+		let mut vals = [U::zero(); 2];
+		for i in 0..2 {
+			vals[i] = match U::from(self[i]) {
+				Some(val) => val,
+				None => return None,
+			};
+		}
+		Some(Vector2::from(vals))
 	}
 }
 
-impl From<[f32; 2]> for Vec2 {
-	fn from(vals: [f32;2]) -> Vec2 {
-		Vec2{
+impl<T: Num + Copy + PartialOrd> Vector2<T> {
+	pub fn min(&self, other: Vector2<T>) -> Vector2<T> {
+		Vector2::from([if self[0] < other[0] { self[0] } else { other[0] }, if self[1] < other[1] { self[1] } else { other[1] }])
+	}
+
+	pub fn max(&self, other: Vector2<T>) -> Vector2<T> {
+		Vector2::from([if self[0] > other[0] { self[0] } else { other[0] }, if self[1] > other[1] { self[1] } else { other[1] }])
+	}
+
+	pub fn clamp(&self, min: Vector2<T>, max: Vector2<T>) -> Vector2<T> {
+		self.max(min).min(max)
+	}
+}
+
+impl<T: Num + Copy + Neg<Output = T>> Vector2<T> {
+	pub fn perpendicular(&self) -> Vector2<T> {
+		Vector2::from([-self[1], self[0]])
+	}
+}
+
+impl<T: Float> Vector2<T> {
+	pub fn length(&self) -> T {
+		self.length_squared().sqrt()
+	}
+
+	pub fn normalize(&self) -> Vector2<T> {
+		let length = self.length();
+		if length == T::zero() {
+			return Vector2::zero();
+		}
+		*self / length
+	}
+
+	pub fn distance(&self, other: Vector2<T>) -> T {
+		(other - *self).length()
+	}
+
+	pub fn lerp(&self, other: Vector2<T>, t: T) -> Vector2<T> {
+		*self + (other - *self) * t
+	}
+
+	pub fn abs(&self) -> Vector2<T> {
+		Vector2::from([self[0].abs(), self[1].abs()])
+	}
+
+	pub fn reflect(&self, normal: Vector2<T>) -> Vector2<T> {
+		*self - normal * ((T::one() + T::one()) * self.dot(normal))
+	}
+
+	pub fn project(&self, onto: Vector2<T>) -> Vector2<T> {
+		let length_squared = onto.length_squared();
+		if length_squared == T::zero() {
+			return Vector2::zero();
+		}
+		onto * (self.dot(onto) / length_squared)
+	}
+
+	pub fn reject(&self, onto: Vector2<T>) -> Vector2<T> {
+		*self - self.project(onto)
+	}
+
+	pub fn approx_eq(&self, other: Vector2<T>, epsilon: T) -> bool {
+		(0..2).all(|i| (self[i] - other[i]).abs() <= epsilon)
+	}
+}
+
+impl<T> From<[T; 2]> for Vector2<T> {
+	fn from(vals: [T; 2]) -> Vector2<T> {
+		Vector2 {
 			vals: vals,
 		}
 	}
 }
 
-impl From<Vec3> for Vec2 {
-	fn from(vec3: Vec3) -> Vec2 {
-		Vec2::from([vec3[0], vec3[1]])
+impl<T: Copy> From<Vector3<T>> for Vector2<T> {
+	fn from(other: Vector3<T>) -> Vector2<T> {
+		Vector2::from([other[0], other[1]])
 	}
 }
 
-impl From<Vec4> for Vec2 {
-	fn from(vec4: Vec4) -> Vec2 {
-		Vec2::from([vec4[0], vec4[1]])
+impl<T: Copy> From<Vector4<T>> for Vector2<T> {
+	fn from(other: Vector4<T>) -> Vector2<T> {
+		Vector2::from([other[0], other[1]])
 	}
 }
 
@@ -67,54 +167,202 @@ impl AsUniformValue for Vec2 {
 	}
 }
 
-impl Index<usize> for Vec2 {
-	type Output = f32;
+impl<T> Index<usize> for Vector2<T> {
+	type Output = T;
 
-	fn index(&self, index: usize) -> &f32 {
+	fn index(&self, index: usize) -> &T {
 		&self.vals[index]
 	}
 }
 
-impl IndexMut<usize> for Vec2 {
-	fn index_mut(&mut self, index: usize) -> &mut f32 {
+impl<T> IndexMut<usize> for Vector2<T> {
+	fn index_mut(&mut self, index: usize) -> &mut T {
 		&mut self.vals[index]
 	}
 }
 
-impl Add<Vec2> for Vec2 {
-	type Output = Vec2;
+impl<T: Num + Copy> Add<Vector2<T>> for Vector2<T> {
+	type Output = Vector2<T>;
 
-	fn add(self, other: Vec2) -> Vec2 {
-		Vec2::from([self[0] + other[0], self[1] + other[1]])
+	fn add(self, other: Vector2<T>) -> Vector2<T> {
+		Vector2::from([self[0] + other[0], self[1] + other[1]])
 	}
 }
 
-impl Sub<Vec2> for Vec2 {
-	type Output = Vec2;
+impl<T: Num + Copy> Add<T> for Vector2<T> {
+	type Output = Vector2<T>;
 
-	fn sub(self, other: Vec2) -> Vec2 {
-		Vec2::from([self[0] - other[0], self[1] - other[1]])
+	fn add(self, other: T) -> Vector2<T> {
+		Vector2::from([self[0] + other, self[1] + other])
 	}
 }
 
-impl Mul<Vec2> for Vec2 {
-	type Output = Vec2;
+impl<T: Num + Copy> Sub<Vector2<T>> for Vector2<T> {
+	type Output = Vector2<T>;
 
-	fn mul(self, other: Vec2) -> Vec2 {
-		Vec2::from([self[0] * other[0], self[1] * other[1]])
+	fn sub(self, other: Vector2<T>) -> Vector2<T> {
+		Vector2::from([self[0] - other[0], self[1] - other[1]])
 	}
 }
 
-impl Mul<f32> for Vec2 {
-	type Output = Vec2;
+impl<T: Num + Copy> Sub<T> for Vector2<T> {
+	type Output = Vector2<T>;
 
-	fn mul(self, other: f32) -> Vec2 {
-		Vec2::from([self[0] * other, self[1] * other])
+	fn sub(self, other: T) -> Vector2<T> {
+		Vector2::from([self[0] - other, self[1] - other])
 	}
 }
 
-impl Display for Vec2 {
+impl<T: Num + Copy> Mul<Vector2<T>> for Vector2<T> {
+	type Output = Vector2<T>;
+
+	fn mul(self, other: Vector2<T>) -> Vector2<T> {
+		Vector2::from([self[0] * other[0], self[1] * other[1]])
+	}
+}
+
+impl<T: Num + Copy> Mul<T> for Vector2<T> {
+	type Output = Vector2<T>;
+
+	fn mul(self, other: T) -> Vector2<T> {
+		Vector2::from([self[0] * other, self[1] * other])
+	}
+}
+
+impl<T: Num + Copy> Div<Vector2<T>> for Vector2<T> {
+	type Output = Vector2<T>;
+
+	fn div(self, other: Vector2<T>) -> Vector2<T> {
+		Vector2::from([self[0] / other[0], self[1] / other[1]])
+	}
+}
+
+impl<T: Num + Copy> Div<T> for Vector2<T> {
+	type Output = Vector2<T>;
+
+	fn div(self, other: T) -> Vector2<T> {
+		Vector2::from([self[0] / other, self[1] / other])
+	}
+}
+
+impl<T: Num + Copy + Neg<Output = T>> Neg for Vector2<T> {
+	type Output = Vector2<T>;
+
+	fn neg(self) -> Vector2<T> {
+		Vector2::from([-self[0], -self[1]])
+	}
+}
+
+impl<T: Num + Copy> AddAssign<Vector2<T>> for Vector2<T> {
+	fn add_assign(&mut self, other: Vector2<T>) {
+		*self = *self + other;
+	}
+}
+
+impl<T: Num + Copy> AddAssign<T> for Vector2<T> {
+	fn add_assign(&mut self, other: T) {
+		*self = *self + other;
+	}
+}
+
+impl<T: Num + Copy> SubAssign<Vector2<T>> for Vector2<T> {
+	fn sub_assign(&mut self, other: Vector2<T>) {
+		*self = *self - other;
+	}
+}
+
+impl<T: Num + Copy> SubAssign<T> for Vector2<T> {
+	fn sub_assign(&mut self, other: T) {
+		*self = *self - other;
+	}
+}
+
+impl<T: Num + Copy> MulAssign<Vector2<T>> for Vector2<T> {
+	fn mul_assign(&mut self, other: Vector2<T>) {
+		*self = *self * other;
+	}
+}
+
+impl<T: Num + Copy> MulAssign<T> for Vector2<T> {
+	fn mul_assign(&mut self, other: T) {
+		*self = *self * other;
+	}
+}
+
+impl<T: Num + Copy> DivAssign<Vector2<T>> for Vector2<T> {
+	fn div_assign(&mut self, other: Vector2<T>) {
+		*self = *self / other;
+	}
+}
+
+impl<T: Num + Copy> DivAssign<T> for Vector2<T> {
+	fn div_assign(&mut self, other: T) {
+		*self = *self / other;
+	}
+}
+
+impl<T: Display> Display for Vector2<T> {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error>{
         write!(f, "({}, {})", self[0], self[1])
     }
 }
+
+#[cfg(test)]
+mod tests {
+	use math::{Vec2, Vector2};
+
+	const EPSILON: f32 = 1e-6;
+
+	#[test]
+	fn cross_is_the_signed_parallelogram_area() {
+		assert_eq!(Vec2::x_unit().cross(Vec2::y_unit()), 1.0);
+		assert_eq!(Vec2::y_unit().cross(Vec2::x_unit()), -1.0);
+		assert_eq!(Vec2::from([2.0, 4.0]).cross(Vec2::from([1.0, 2.0])), 0.0);
+	}
+
+	#[test]
+	fn dot_sums_the_component_products() {
+		assert_eq!(Vec2::from([1.0, 2.0]).dot(Vec2::from([3.0, -4.0])), -5.0);
+		assert_eq!(Vec2::x_unit().dot(Vec2::y_unit()), 0.0);
+		assert_eq!(Vector2::from([2, 3]).dot(Vector2::from([4, 5])), 23);
+	}
+
+	#[test]
+	fn perpendicular_turns_a_quarter_counter_clockwise() {
+		let v = Vec2::from([3.0, 1.0]);
+		let perpendicular = v.perpendicular();
+		assert_eq!(perpendicular, Vec2::from([-1.0, 3.0]));
+		assert_eq!(v.dot(perpendicular), 0.0);
+		assert_eq!(v.cross(perpendicular), v.length_squared());
+		assert_eq!(Vec2::x_unit().perpendicular(), Vec2::y_unit());
+	}
+
+	#[test]
+	fn length_and_distance_are_euclidean() {
+		let a = Vec2::from([1.0, 1.0]);
+		let b = Vec2::from([4.0, 5.0]);
+		assert_eq!(Vec2::from([3.0, -4.0]).length(), 5.0);
+		assert_eq!(a.distance(b), 5.0);
+		assert_eq!(b.distance(a), 5.0);
+		assert_eq!(a.distance_squared(b), 25.0);
+		assert_eq!(a.distance(a), 0.0);
+	}
+
+	#[test]
+	fn cast_converts_every_component_or_none() {
+		assert_eq!(Vec2::from([1.9, -2.5]).cast::<i32>(), Some(Vector2::from([1, -2])));
+		assert_eq!(Vector2::from([3u8, 7u8]).cast::<f32>(), Some(Vec2::from([3.0, 7.0])));
+		assert_eq!(Vec2::from([1.0, -1.0]).cast::<u8>(), None);
+		assert_eq!(Vec2::from([::std::f32::NAN, 0.0]).cast::<i32>(), None);
+	}
+
+	#[test]
+	fn lerp_interpolates_and_extrapolates() {
+		let a = Vec2::from([0.0, 2.0]);
+		let b = Vec2::from([4.0, -2.0]);
+		assert!(a.lerp(b, 0.0).approx_eq(a, EPSILON));
+		assert!(a.lerp(b, 1.0).approx_eq(b, EPSILON));
+		assert!(a.lerp(b, 0.25).approx_eq(Vec2::from([1.0, 1.0]), EPSILON));
+		assert!(a.lerp(b, 1.5).approx_eq(Vec2::from([6.0, -4.0]), EPSILON));
+	}
+}
@@ -1,53 +1,155 @@
 use glium::uniforms::{AsUniformValue, UniformValue};
-use std::ops::{Index, IndexMut, Add, Sub, Mul};
+use num::{Num, Float, NumCast};
+use std::ops::{Index, IndexMut, Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
 use std::fmt::{Display, Formatter, Error};
 
-use math::{Vec4};
+use math::{Vector4};
+
+pub type Vec3 = Vector3<f32>;
 
 #[derive(Copy, Clone, PartialEq, Debug)]
-pub struct Vec3 {
-	vals: [f32; 3],
+pub struct Vector3<T> {
+	vals: [T; 3],
 }
 
-impl Vec3 {
-	pub fn zero() -> Vec3 {
-		Vec3::from([0.0; 3])
+impl<T: Num + Copy> Vector3<T> {
+	pub fn zero() -> Vector3<T> {
+		Vector3::from([T::zero(); 3])
+	}
+
+	pub fn one() -> Vector3<T> {
+		Vector3::from([T::one(); 3])
+	}
+
+	pub fn x_unit() -> Vector3<T> {
+		Vector3::from([T::one(), T::zero(), T::zero()])
+	}
+
+	pub fn y_unit() -> Vector3<T> {
+		Vector3::from([T::zero(), T::one(), T::zero()])
 	}
 
-	pub fn one() -> Vec3 {
-		Vec3::from([1.0; 3])
+	pub fn z_unit() -> Vector3<T> {
+		Vector3::from([T::zero(), T::zero(), T::one()])
 	}
 
-	pub fn get_vals(&self) -> [f32; 3] {
+	pub fn get_vals(&self) -> [T; 3] {
 		self.vals
 	}
 
-	pub fn dot(&self, other: Vec3) -> f32 {
-		let mut sum = 0.0;
+	pub fn dot(&self, other: Vector3<T>) -> T {
+		let mut sum = T::zero();
 
 		for i in 0..3 {
-			sum += self[i] * other[i];
+			sum = sum + self[i] * other[i];
 		}
 
 		sum
 	}
 
-	pub fn to_vec4(&self, w: f32) -> Vec4 {
-		Vec4::from([self[0], self[1], self[2], w])
+	pub fn to_vec4(&self, w: T) -> Vector4<T> {
+		Vector4::from([self[0], self[1], self[2], w])
+	}
+
+	pub fn cross(&self, other: Vector3<T>) -> Vector3<T> {
+		Vector3::from([
+			self[1] * other[2] - self[2] * other[1],
+			self[2] * other[0] - self[0] * other[2],
+			self[0] * other[1] - self[1] * other[0],
+		])
+	}
+
+	pub fn length_squared(&self) -> T {
+		self.dot(*self)
+	}
+
+	pub fn distance_squared(&self, other: Vector3<T>) -> T {
+		(other - *self).length_squared()
+	}
+
+	pub fn cast<U: Num + Copy + NumCast>(&self) -> Option<Vector3<U>> where T: NumCast {
+		let mut vals = [U::zero(); 3];
+		for i in 0..3 {
+			vals[i] = match U::from(self[i]) {
+				Some(val) => val,
+				None => return None,
+			};
+		}
+		Some(Vector3::from(vals))
 	}
 }
 
-impl From<[f32; 3]> for Vec3 {
-	fn from(vals: [f32; 3]) -> Vec3 {
-		Vec3{
+impl<T: Num + Copy + PartialOrd> Vector3<T> {
+	pub fn min(&self, other: Vector3<T>) -> Vector3<T> {
+		Vector3::from([if self[0] < other[0] { self[0] } else { other[0] }, if self[1] < other[1] { self[1] } else { other[1] }, if self[2] < other[2] { self[2] } else { other[2] }])
+	}
+
+	pub fn max(&self, other: Vector3<T>) -> Vector3<T> {
+		Vector3::from([if self[0] > other[0] { self[0] } else { other[0] }, if self[1] > other[1] { self[1] } else { other[1] }, if self[2] > other[2] { self[2] } else { other[2] }])
+	}
+
+	pub fn clamp(&self, min: Vector3<T>, max: Vector3<T>) -> Vector3<T> {
+		self.max(min).min(max)
+	}
+}
+
+impl<T: Float> Vector3<T> {
+	pub fn length(&self) -> T {
+		self.length_squared().sqrt()
+	}
+
+	pub fn normalize(&self) -> Vector3<T> {
+		let length = self.length();
+		if length == T::zero() {
+			return Vector3::zero();
+		}
+		*self / length
+	}
+
+	pub fn distance(&self, other: Vector3<T>) -> T {
+		(other - *self).length()
+	}
+
+	pub fn lerp(&self, other: Vector3<T>, t: T) -> Vector3<T> {
+		*self + (other - *self) * t
+	}
+
+	pub fn abs(&self) -> Vector3<T> {
+		Vector3::from([self[0].abs(), self[1].abs(), self[2].abs()])
+	}
+
+	pub fn reflect(&self, normal: Vector3<T>) -> Vector3<T> {
+		*self - normal * ((T::one() + T::one()) * self.dot(normal))
+	}
+
+	pub fn project(&self, onto: Vector3<T>) -> Vector3<T> {
+		let length_squared = onto.length_squared();
+		if length_squared == T::zero() {
+			return Vector3::zero();
+		}
+		onto * (self.dot(onto) / length_squared)
+	}
+
+	pub fn reject(&self, onto: Vector3<T>) -> Vector3<T> {
+		*self - self.project(onto)
+	}
+
+	pub fn approx_eq(&self, other: Vector3<T>, epsilon: T) -> bool {
+		(0..3).all(|i| (self[i] - other[i]).abs() <= epsilon)
+	}
+}
+
+impl<T> From<[T; 3]> for Vector3<T> {
+	fn from(vals: [T; 3]) -> Vector3<T> {
+		Vector3 {
 			vals: vals,
 		}
 	}
 }
 
-impl From<Vec4> for Vec3 {
-	fn from(vec4: Vec4) -> Vec3 {
-		Vec3::from([vec4[0], vec4[1], vec4[2]])
+impl<T: Copy> From<Vector4<T>> for Vector3<T> {
+	fn from(other: Vector4<T>) -> Vector3<T> {
+		Vector3::from([other[0], other[1], other[2]])
 	}
 }
 
@@ -57,54 +159,205 @@ impl AsUniformValue for Vec3 {
 	}
 }
 
-impl Index<usize> for Vec3 {
-	type Output = f32;
+impl<T> Index<usize> for Vector3<T> {
+	type Output = T;
 
-	fn index(&self, index: usize) -> &f32 {
+	fn index(&self, index: usize) -> &T {
 		&self.vals[index]
 	}
 }
 
-impl IndexMut<usize> for Vec3 {
-	fn index_mut(&mut self, index: usize) -> &mut f32 {
+impl<T> IndexMut<usize> for Vector3<T> {
+	fn index_mut(&mut self, index: usize) -> &mut T {
 		&mut self.vals[index]
 	}
 }
 
-impl Add<Vec3> for Vec3 {
-	type Output = Vec3;
+impl<T: Num + Copy> Add<Vector3<T>> for Vector3<T> {
+	type Output = Vector3<T>;
+
+	fn add(self, other: Vector3<T>) -> Vector3<T> {
+		Vector3::from([self[0] + other[0], self[1] + other[1], self[2] + other[2]])
+	}
+}
+
+impl<T: Num + Copy> Add<T> for Vector3<T> {
+	type Output = Vector3<T>;
+
+	fn add(self, other: T) -> Vector3<T> {
+		Vector3::from([self[0] + other, self[1] + other, self[2] + other])
+	}
+}
+
+impl<T: Num + Copy> Sub<Vector3<T>> for Vector3<T> {
+	type Output = Vector3<T>;
+
+	fn sub(self, other: Vector3<T>) -> Vector3<T> {
+		Vector3::from([self[0] - other[0], self[1] - other[1], self[2] - other[2]])
+	}
+}
+
+impl<T: Num + Copy> Sub<T> for Vector3<T> {
+	type Output = Vector3<T>;
+
+	fn sub(self, other: T) -> Vector3<T> {
+		Vector3::from([self[0] - other, self[1] - other, self[2] - other])
+	}
+}
+
+impl<T: Num + Copy> Mul<Vector3<T>> for Vector3<T> {
+	type Output = Vector3<T>;
+
+	fn mul(self, other: Vector3<T>) -> Vector3<T> {
+		Vector3::from([self[0] * other[0], self[1] * other[1], self[2] * other[2]])
+	}
+}
+
+impl<T: Num + Copy> Mul<T> for Vector3<T> {
+	type Output = Vector3<T>;
 
-	fn add(self, other: Vec3) -> Vec3 {
-		Vec3::from([self[0] + other[0], self[1] + other[1], self[2] + other[2]])
+	fn mul(self, other: T) -> Vector3<T> {
+		Vector3::from([self[0] * other, self[1] * other, self[2] * other])
 	}
 }
 
-impl Sub<Vec3> for Vec3 {
-	type Output = Vec3;
+impl<T: Num + Copy> Div<Vector3<T>> for Vector3<T> {
+	type Output = Vector3<T>;
 
-	fn sub(self, other: Vec3) -> Vec3 {
-		Vec3::from([self[0] - other[0], self[1] - other[1], self[2] - other[2]])
+	fn div(self, other: Vector3<T>) -> Vector3<T> {
+		Vector3::from([self[0] / other[0], self[1] / other[1], self[2] / other[2]])
 	}
 }
 
-impl Mul<Vec3> for Vec3 {
-	type Output = Vec3;
+impl<T: Num + Copy> Div<T> for Vector3<T> {
+	type Output = Vector3<T>;
 
-	fn mul(self, other: Vec3) -> Vec3 {
-		Vec3::from([self[0] * other[0], self[1] * other[1], self[2] * other[2]])
+	fn div(self, other: T) -> Vector3<T> {
+		Vector3::from([self[0] / other, self[1] / other, self[2] / other])
 	}
 }
 
-impl Mul<f32> for Vec3 {
-	type Output = Vec3;
+impl<T: Num + Copy + Neg<Output = T>> Neg for Vector3<T> {
+	type Output = Vector3<T>;
+
+	fn neg(self) -> Vector3<T> {
+		Vector3::from([-self[0], -self[1], -self[2]])
+	}
+}
+
+impl<T: Num + Copy> AddAssign<Vector3<T>> for Vector3<T> {
+	fn add_assign(&mut self, other: Vector3<T>) {
+		*self = *self + other;
+	}
+}
+
+impl<T: Num + Copy> AddAssign<T> for Vector3<T> {
+	fn add_assign(&mut self, other: T) {
+		*self = *self + other;
+	}
+}
+
+impl<T: Num + Copy> SubAssign<Vector3<T>> for Vector3<T> {
+	fn sub_assign(&mut self, other: Vector3<T>) {
+		*self = *self - other;
+	}
+}
 
-	fn mul(self, other: f32) -> Vec3 {
-		Vec3::from([self[0] * other, self[1] * other, self[2] * other])
+impl<T: Num + Copy> SubAssign<T> for Vector3<T> {
+	fn sub_assign(&mut self, other: T) {
+		*self = *self - other;
 	}
 }
 
-impl Display for Vec3 {
+impl<T: Num + Copy> MulAssign<Vector3<T>> for Vector3<T> {
+	fn mul_assign(&mut self, other: Vector3<T>) {
+		*self = *self * other;
+	}
+}
+
+impl<T: Num + Copy> MulAssign<T> for Vector3<T> {
+	fn mul_assign(&mut self, other: T) {
+		*self = *self * other;
+	}
+}
+
+impl<T: Num + Copy> DivAssign<Vector3<T>> for Vector3<T> {
+	fn div_assign(&mut self, other: Vector3<T>) {
+		*self = *self / other;
+	}
+}
+
+impl<T: Num + Copy> DivAssign<T> for Vector3<T> {
+	fn div_assign(&mut self, other: T) {
+		*self = *self / other;
+	}
+}
+
+impl<T: Display> Display for Vector3<T> {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error>{
         write!(f, "({}, {}, {})", self[0], self[1], self[2])
     }
 }
+
+#[cfg(test)]
+mod tests {
+	use math::{Vec3};
+
+	const EPSILON: f32 = 1e-6;
+
+	#[test]
+	fn cross_follows_the_right_hand_rule() {
+		assert!(Vec3::x_unit().cross(Vec3::y_unit()).approx_eq(Vec3::z_unit(), EPSILON));
+		assert!(Vec3::y_unit().cross(Vec3::z_unit()).approx_eq(Vec3::x_unit(), EPSILON));
+		assert!(Vec3::y_unit().cross(Vec3::x_unit()).approx_eq(-Vec3::z_unit(), EPSILON));
+		let a = Vec3::from([1.0, 2.0, 3.0]);
+		let b = Vec3::from([-2.0, 0.5, 4.0]);
+		let c = a.cross(b);
+		assert!(c.dot(a).abs() <= EPSILON * 10.0 && c.dot(b).abs() <= EPSILON * 10.0);
+		assert!(a.cross(a * 2.0).approx_eq(Vec3::zero(), EPSILON));
+	}
+
+	#[test]
+	fn normalize_keeps_the_zero_vector() {
+		assert_eq!(Vec3::zero().normalize(), Vec3::zero());
+		assert!(Vec3::from([0.0, 3.0, -4.0]).normalize().approx_eq(Vec3::from([0.0, 0.6, -0.8]), EPSILON));
+	}
+
+	#[test]
+	fn reflect_flips_the_normal_component() {
+		let reflected = Vec3::from([1.0, -2.0, 3.0]).reflect(Vec3::y_unit());
+		assert!(reflected.approx_eq(Vec3::from([1.0, 2.0, 3.0]), EPSILON));
+	}
+
+	#[test]
+	fn project_and_reject_split_the_vector() {
+		let v = Vec3::from([1.0, 2.0, 3.0]);
+		let onto = Vec3::from([0.0, 0.0, 5.0]);
+		assert!(v.project(onto).approx_eq(Vec3::from([0.0, 0.0, 3.0]), EPSILON));
+		assert!(v.reject(onto).approx_eq(Vec3::from([1.0, 2.0, 0.0]), EPSILON));
+		assert_eq!(v.project(Vec3::zero()), Vec3::zero());
+		assert_eq!(v.reject(Vec3::zero()), v);
+	}
+
+	#[test]
+	fn compound_assignment_matches_the_binary_operators() {
+		let a = Vec3::from([1.0, 2.0, -3.0]);
+		let b = Vec3::from([4.0, -8.0, 0.5]);
+		let mut v = a;
+		v += b;
+		assert!(v.approx_eq(a + b, EPSILON));
+		v -= b;
+		assert!(v.approx_eq(a, EPSILON));
+		v *= b;
+		assert!(v.approx_eq(a * b, EPSILON));
+		v /= b;
+		assert!(v.approx_eq(a, EPSILON));
+		v += 1.0;
+		assert!(v.approx_eq(a + 1.0, EPSILON));
+		v -= 1.0;
+		v *= 3.0;
+		assert!(v.approx_eq(a * 3.0, EPSILON));
+		v /= 3.0;
+		assert!(v.approx_eq(a, EPSILON));
+	}
+}
@@ -1,45 +1,137 @@
 use glium::uniforms::{AsUniformValue, UniformValue};
-use std::ops::{Index, IndexMut, Add, Sub, Mul};
+use num::{Num, Float, NumCast};
+use std::ops::{Index, IndexMut, Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
 use std::fmt::{Display, Formatter, Error};
 
+pub type Vec4 = Vector4<f32>;
+
 #[derive(Copy, Clone, PartialEq, Debug)]
-pub struct Vec4 {
-	vals: [f32; 4],
+pub struct Vector4<T> {
+	vals: [T; 4],
 }
 
-impl Vec4 {
-	pub fn zero() -> Vec4 {
-		Vec4::from([0.0; 4])
+impl<T: Num + Copy> Vector4<T> {
+	pub fn zero() -> Vector4<T> {
+		Vector4::from([T::zero(); 4])
 	}
 
-	pub fn one() -> Vec4 {
-		Vec4::from([1.0; 4])
+	pub fn one() -> Vector4<T> {
+		Vector4::from([T::one(); 4])
 	}
 
-	pub fn x_unit() -> Vec4 {
-		Vec4::from([1.0, 0.0, 0.0, 0.0])
+	pub fn x_unit() -> Vector4<T> {
+		Vector4::from([T::one(), T::zero(), T::zero(), T::zero()])
 	}
 
-	pub fn y_unit() -> Vec4 {
-		Vec4::from([0.0, 1.0, 0.0, 0.0])
+	pub fn y_unit() -> Vector4<T> {
+		Vector4::from([T::zero(), T::one(), T::zero(), T::zero()])
 	}
 
-	pub fn z_unit() -> Vec4 {
-		Vec4::from([0.0, 0.0, 1.0, 0.0])
+	pub fn z_unit() -> Vector4<T> {
+		Vector4::from([T::zero(), T::zero(), T::one(), T::zero()])
 	}
 
-	pub fn w_unit() -> Vec4 {
-		Vec4::from([0.0, 0.0, 0.0, 1.0])
+	pub fn w_unit() -> Vector4<T> {
+		Vector4::from([T::zero(), T::zero(), T::zero(), T::one()])
 	}
 
-	pub fn get_vals(&self) -> [f32; 4] {
+	pub fn get_vals(&self) -> [T; 4] {
 		self.vals
 	}
+
+	pub fn dot(&self, other: Vector4<T>) -> T {
+		let mut sum = T::zero();
+
+		for i in 0..4 {
+			sum = sum + self[i] * other[i];
+		}
+
+		sum
+	}
+
+	pub fn length_squared(&self) -> T {
+		self.dot(*self)
+	}
+
+	pub fn distance_squared(&self, other: Vector4<T>) -> T {
+		(other - *self).length_squared()
+	}
+
+	pub fn cast<U: Num + Copy + NumCast>(&self) -> Option<Vector4<U>> where T: NumCast {
+		let mut vals = [U::zero(); 4];
+		for i in 0..4 {
+			vals[i] = match U::from(self[i]) {
+				Some(val) => val,
+				None => return None,
+			};
+		}
+		Some(Vector4::from(vals))
+	}
+}
+
+impl<T: Num + Copy + PartialOrd> Vector4<T> {
+	pub fn min(&self, other: Vector4<T>) -> Vector4<T> {
+		Vector4::from([if self[0] < other[0] { self[0] } else { other[0] }, if self[1] < other[1] { self[1] } else { other[1] }, if self[2] < other[2] { self[2] } else { other[2] }, if self[3] < other[3] { self[3] } else { other[3] }])
+	}
+
+	pub fn max(&self, other: Vector4<T>) -> Vector4<T> {
+		Vector4::from([if self[0] > other[0] { self[0] } else { other[0] }, if self[1] > other[1] { self[1] } else { other[1] }, if self[2] > other[2] { self[2] } else { other[2] }, if self[3] > other[3] { self[3] } else { other[3] }])
+	}
+
+	pub fn clamp(&self, min: Vector4<T>, max: Vector4<T>) -> Vector4<T> {
+		self.max(min).min(max)
+	}
+}
+
+impl<T: Float> Vector4<T> {
+	pub fn length(&self) -> T {
+		self.length_squared().sqrt()
+	}
+
+	pub fn normalize(&self) -> Vector4<T> {
+		let length = self.length();
+		if length == T::zero() {
+			return Vector4::zero();
+		}
+		*self / length
+	}
+
+	pub fn distance(&self, other: Vector4<T>) -> T {
+		(other - *self).length()
+	}
+
+	pub fn lerp(&self, other: Vector4<T>, t: T) -> Vector4<T> {
+		*self + (other - *self) * t
+	}
+
+	pub fn abs(&self) -> Vector4<T> {
+		Vector4::from([self[0].abs(), self[1].abs(), self[2].abs(), self[3].abs()])
+	}
+
+	pub fn reflect(&self, normal: Vector4<T>) -> Vector4<T> {
+		*self - normal * ((T::one() + T::one()) * self.dot(normal))
+	}
+
+	pub fn project(&self, onto: Vector4<T>) -> Vector4<T> {
+		let length_squared = onto.length_squared();
+		if length_squared == T::zero() {
+			return Vector4::zero();
+		}
+		onto * (self.dot(onto) / length_squared)
+	}
+
+	pub fn reject(&self, onto: Vector4<T>) -> Vector4<T> {
+		*self - self.project(onto)
+	}
+
+	pub fn approx_eq(&self, other: Vector4<T>, epsilon: T) -> bool {
+		(0..4).all(|i| (self[i] - other[i]).abs() <= epsilon)
+	}
 }
 
-impl From<[f32; 4]> for Vec4 {
-	fn from(vals: [f32; 4]) -> Vec4 {
-		Vec4 {
+impl<T> From<[T; 4]> for Vector4<T> {
+	fn from(vals: [T; 4]) -> Vector4<T> {
+		Vector4 {
 			vals: vals,
 		}
 	}
@@ -51,54 +143,185 @@ impl AsUniformValue for Vec4 {
 	}
 }
 
-impl Index<usize> for Vec4 {
-	type Output = f32;
+impl<T> Index<usize> for Vector4<T> {
+	type Output = T;
 
-	fn index(&self, index: usize) -> &f32 {
+	fn index(&self, index: usize) -> &T {
 		&self.vals[index]
 	}
 }
 
-impl IndexMut<usize> for Vec4 {
-	fn index_mut(&mut self, index: usize) -> &mut f32 {
+impl<T> IndexMut<usize> for Vector4<T> {
+	fn index_mut(&mut self, index: usize) -> &mut T {
 		&mut self.vals[index]
 	}
 }
 
-impl Add<Vec4> for Vec4{
-	type Output = Vec4;
+impl<T: Num + Copy> Add<Vector4<T>> for Vector4<T> {
+	type Output = Vector4<T>;
+
+	fn add(self, other: Vector4<T>) -> Vector4<T> {
+		Vector4::from([self[0] + other[0], self[1] + other[1], self[2] + other[2], self[3] + other[3]])
+	}
+}
+
+impl<T: Num + Copy> Add<T> for Vector4<T> {
+	type Output = Vector4<T>;
 
-	fn add(self, other: Vec4) -> Vec4 {
-		Vec4::from([self[0] + other[0], self[1] + other[1], self[2] + other[2], self[3] + other[3]])
+	fn add(self, other: T) -> Vector4<T> {
+		Vector4::from([self[0] + other, self[1] + other, self[2] + other, self[3] + other])
 	}
 }
 
-impl Sub<Vec4> for Vec4 {
-	type Output = Vec4;
+impl<T: Num + Copy> Sub<Vector4<T>> for Vector4<T> {
+	type Output = Vector4<T>;
 
-	fn sub(self, other: Vec4) -> Vec4 {
-		Vec4::from([self[0] - other[0], self[1] - other[1], self[2] - other[2], self[3] - other[3]])
+	fn sub(self, other: Vector4<T>) -> Vector4<T> {
+		Vector4::from([self[0] - other[0], self[1] - other[1], self[2] - other[2], self[3] - other[3]])
 	}
 }
 
-impl Mul<Vec4> for Vec4 {
-	type Output = Vec4;
+impl<T: Num + Copy> Sub<T> for Vector4<T> {
+	type Output = Vector4<T>;
 
-	fn mul(self, other: Vec4) -> Vec4 {
-		Vec4::from([self[0] * other[0], self[1] * other[1], self[2] * other[2], self[3] * other[3]])
+	fn sub(self, other: T) -> Vector4<T> {
+		Vector4::from([self[0] - other, self[1] - other, self[2] - other, self[3] - other])
 	}
 }
 
-impl Mul<f32> for Vec4 {
-	type Output = Vec4;
+impl<T: Num + Copy> Mul<Vector4<T>> for Vector4<T> {
+	type Output = Vector4<T>;
 
-	fn mul(self, other: f32) -> Vec4 {
-		Vec4::from([self[0] * other, self[1] * other, self[2] * other, self[3] * other])
+	fn mul(self, other: Vector4<T>) -> Vector4<T> {
+		Vector4::from([self[0] * other[0], self[1] * other[1], self[2] * other[2], self[3] * other[3]])
 	}
 }
 
-impl Display for Vec4 {
+impl<T: Num + Copy> Mul<T> for Vector4<T> {
+	type Output = Vector4<T>;
+
+	fn mul(self, other: T) -> Vector4<T> {
+		Vector4::from([self[0] * other, self[1] * other, self[2] * other, self[3] * other])
+	}
+}
+
+impl<T: Num + Copy> Div<Vector4<T>> for Vector4<T> {
+	type Output = Vector4<T>;
+
+	fn div(self, other: Vector4<T>) -> Vector4<T> {
+		Vector4::from([self[0] / other[0], self[1] / other[1], self[2] / other[2], self[3] / other[3]])
+	}
+}
+
+impl<T: Num + Copy> Div<T> for Vector4<T> {
+	type Output = Vector4<T>;
+
+	fn div(self, other: T) -> Vector4<T> {
+		Vector4::from([self[0] / other, self[1] / other, self[2] / other, self[3] / other])
+	}
+}
+
+impl<T: Num + Copy + Neg<Output = T>> Neg for Vector4<T> {
+	type Output = Vector4<T>;
+
+	fn neg(self) -> Vector4<T> {
+		Vector4::from([-self[0], -self[1], -self[2], -self[3]])
+	}
+}
+
+impl<T: Num + Copy> AddAssign<Vector4<T>> for Vector4<T> {
+	fn add_assign(&mut self, other: Vector4<T>) {
+		*self = *self + other;
+	}
+}
+
+impl<T: Num + Copy> AddAssign<T> for Vector4<T> {
+	fn add_assign(&mut self, other: T) {
+		*self = *self + other;
+	}
+}
+
+impl<T: Num + Copy> SubAssign<Vector4<T>> for Vector4<T> {
+	fn sub_assign(&mut self, other: Vector4<T>) {
+		*self = *self - other;
+	}
+}
+
+impl<T: Num + Copy> SubAssign<T> for Vector4<T> {
+	fn sub_assign(&mut self, other: T) {
+		*self = *self - other;
+	}
+}
+
+impl<T: Num + Copy> MulAssign<Vector4<T>> for Vector4<T> {
+	fn mul_assign(&mut self, other: Vector4<T>) {
+		*self = *self * other;
+	}
+}
+
+impl<T: Num + Copy> MulAssign<T> for Vector4<T> {
+	fn mul_assign(&mut self, other: T) {
+		*self = *self * other;
+	}
+}
+
+impl<T: Num + Copy> DivAssign<Vector4<T>> for Vector4<T> {
+	fn div_assign(&mut self, other: Vector4<T>) {
+		*self = *self / other;
+	}
+}
+
+impl<T: Num + Copy> DivAssign<T> for Vector4<T> {
+	fn div_assign(&mut self, other: T) {
+		*self = *self / other;
+	}
+}
+
+impl<T: Display> Display for Vector4<T> {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error>{
         write!(f, "({}, {}, {}, {})", self[0], self[1], self[2], self[3])
     }
 }
+
+#[cfg(test)]
+mod tests {
+	use math::{Vec4, Vector4};
+
+	#[test]
+	fn dot_sums_the_component_products() {
+		let a = Vec4::from([1.0, 2.0, 3.0, 4.0]);
+		assert_eq!(a.dot(Vec4::from([-1.0, 0.5, 2.0, -0.25])), 5.0);
+		assert_eq!(a.dot(Vec4::w_unit()), 4.0);
+		assert_eq!(Vec4::x_unit().dot(Vec4::z_unit()), 0.0);
+		assert_eq!(a.dot(a), a.length_squared());
+	}
+
+	#[test]
+	fn length_and_distance_are_euclidean() {
+		let a = Vec4::from([1.0, 1.0, 1.0, 1.0]);
+		let b = Vec4::from([2.0, 3.0, 1.0, -1.0]);
+		assert_eq!(Vec4::one().length(), 2.0);
+		assert_eq!(Vec4::from([1.0, -2.0, 2.0, 4.0]).length(), 5.0);
+		assert_eq!(a.distance(b), 3.0);
+		assert_eq!(b.distance(a), 3.0);
+		assert_eq!(a.distance_squared(b), 9.0);
+	}
+
+	#[test]
+	fn min_max_and_abs_work_per_component() {
+		let a = Vec4::from([-5.0, 0.5, 7.0, 1.0]);
+		let b = Vec4::from([0.0, 2.0, -1.0, 1.0]);
+		assert_eq!(a.min(b), Vec4::from([-5.0, 0.5, -1.0, 1.0]));
+		assert_eq!(a.max(b), Vec4::from([0.0, 2.0, 7.0, 1.0]));
+		assert_eq!(a.min(b), b.min(a));
+		assert_eq!(a.abs(), Vec4::from([5.0, 0.5, 7.0, 1.0]));
+		assert_eq!(a.clamp(Vec4::zero(), Vec4::one()), Vec4::from([0.0, 0.5, 1.0, 1.0]));
+	}
+
+	#[test]
+	fn cast_converts_every_component_or_none() {
+		assert_eq!(Vector4::from([1, -2, 3, 4]).cast::<f32>(), Some(Vec4::from([1.0, -2.0, 3.0, 4.0])));
+		assert_eq!(Vec4::from([0.5, 1.5, 2.5, 255.0]).cast::<u8>(), Some(Vector4::from([0u8, 1, 2, 255])));
+		assert_eq!(Vec4::from([0.0, 0.0, 0.0, 256.0]).cast::<u8>(), None);
+	}
+}
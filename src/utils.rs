@@ -1,3 +1,90 @@
 pub use glium::glutin::ElementState as ButtonState;
 pub use glium::glutin::VirtualKeyCode as KeyCode;
 pub use glium::glutin::MouseButton as MouseButton;
+pub use glium::glutin::TouchPhase as TouchPhase;
+pub use glium::glutin::CursorState as CursorState;
+pub use glium::glutin::Event as WindowEvent;
+
+macro_rules! key_codes {
+    ($($name:ident),*) => {
+        pub const KEY_CODES: &'static [KeyCode] = &[$(KeyCode::$name),*];
+
+        pub fn key_code_name(key_code: KeyCode) -> String {
+            match key_code {
+                $(KeyCode::$name => stringify!($name).to_string()),*
+            }
+        }
+
+        pub fn key_code_from_name(name: &str) -> Option<KeyCode> {
+            match name {
+                $(stringify!($name) => Some(KeyCode::$name)),*,
+                _ => None,
+            }
+        }
+    }
+}
+
+key_codes!(
+    Key1, Key2, Key3, Key4, Key5, Key6, Key7, Key8, Key9, Key0,
+    A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+    Escape, F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12, F13, F14, F15,
+    Snapshot, Scroll, Pause, Insert, Home, Delete, End, PageDown, PageUp,
+    Left, Up, Right, Down, Back, Return, Space, Compose, Numlock,
+    Numpad0, Numpad1, Numpad2, Numpad3, Numpad4, Numpad5, Numpad6, Numpad7, Numpad8, Numpad9,
+    AbntC1, AbntC2, Add, Apostrophe, Apps, At, Ax, Backslash, Calculator, Capital, Colon, Comma,
+    Convert, Decimal, Divide, Equals, Grave, Kana, Kanji, LAlt, LBracket, LControl, LMenu, LShift,
+    LWin, Mail, MediaSelect, MediaStop, Minus, Multiply, Mute, MyComputer, NavigateForward,
+    NavigateBackward, NextTrack, NoConvert, NumpadComma, NumpadEnter, NumpadEquals, OEM102,
+    Period, PlayPause, Power, PrevTrack, RAlt, RBracket, RControl, RMenu, RShift, RWin,
+    Semicolon, Slash, Sleep, Stop, Subtract, Sysrq, Tab, Underline, Unlabeled, VolumeDown,
+    VolumeUp, Wake, WebBack, WebFavorites, WebForward, WebHome, WebRefresh, WebSearch, WebStop, Yen
+);
+
+pub fn mouse_button_name(mouse_button: MouseButton) -> String {
+    match mouse_button {
+        MouseButton::Left => "Left".to_string(),
+        MouseButton::Right => "Right".to_string(),
+        MouseButton::Middle => "Middle".to_string(),
+        MouseButton::Other(other) => other.to_string(),
+    }
+}
+
+pub fn mouse_button_from_name(name: &str) -> Option<MouseButton> {
+    match name {
+        "Left" => Some(MouseButton::Left),
+        "Right" => Some(MouseButton::Right),
+        "Middle" => Some(MouseButton::Middle),
+        _ => name.parse().ok().map(MouseButton::Other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::{MouseButton, KEY_CODES, key_code_name, key_code_from_name, mouse_button_name, mouse_button_from_name};
+
+    #[test]
+    fn every_key_code_round_trips_through_its_name() {
+        for key_code in KEY_CODES.iter() {
+            let name = key_code_name(*key_code);
+            assert_eq!(name, format!("{:?}", key_code));
+            assert_eq!(key_code_from_name(&name), Some(*key_code));
+        }
+    }
+
+    #[test]
+    fn key_codes_missing_from_the_old_table_load() {
+        for name in ["Mute", "VolumeUp", "Compose", "Sysrq", "Kana", "AbntC1"].iter() {
+            assert!(key_code_from_name(name).is_some(), "{} should load", name);
+        }
+        assert_eq!(key_code_from_name("Unknown"), None);
+        assert_eq!(key_code_from_name("a"), None);
+        assert_eq!(key_code_from_name(""), None);
+    }
+
+    #[test]
+    fn mouse_buttons_round_trip_through_their_names() {
+        for button in [MouseButton::Left, MouseButton::Right, MouseButton::Middle, MouseButton::Other(7)].iter() {
+            assert_eq!(mouse_button_from_name(&mouse_button_name(*button)), Some(*button));
+        }
+    }
+}
@@ -0,0 +1,186 @@
+extern crate porp;
+
+use std::collections::{HashMap};
+use std::env;
+use std::fs::{File, remove_file};
+use std::io::{Write};
+use std::path::{PathBuf};
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::{Duration};
+use porp::{init, Window, HeadlessBackend, HeadlessLog, Upload, Being, Entity, ID, RenderUpdateData, AssetError};
+use common::{TestType, TestBeing};
+
+mod common;
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFFFFFFu32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB88320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in bytes {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+fn be32(value: u32) -> Vec<u8> {
+    vec!((value >> 24) as u8, (value >> 16) as u8, (value >> 8) as u8, value as u8)
+}
+
+fn chunk(png: &mut Vec<u8>, kind: &[u8], data: &[u8]) {
+    png.extend(be32(data.len() as u32));
+    let mut body = kind.to_vec();
+    body.extend_from_slice(data);
+    png.extend_from_slice(&body);
+    png.extend(be32(crc32(&body)));
+}
+
+fn png(width: u32, height: u32, color: [u8; 4]) -> Vec<u8> {
+    let mut raw = vec!();
+    for _ in 0..height {
+        raw.push(0);
+        for _ in 0..width {
+            raw.extend_from_slice(&color);
+        }
+    }
+    let mut zlib = vec!(0x78, 0x01, 0x01, raw.len() as u8, (raw.len() >> 8) as u8, !(raw.len() as u8), !((raw.len() >> 8) as u8));
+    zlib.extend_from_slice(&raw);
+    zlib.extend(be32(adler32(&raw)));
+    let mut header = be32(width);
+    header.extend(be32(height));
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);
+    let mut png = vec!(0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n');
+    chunk(&mut png, b"IHDR", &header);
+    chunk(&mut png, b"IDAT", &zlib);
+    chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    fn new(name: &str, data: &[u8]) -> TempFile {
+        let file = TempFile {
+            path: env::temp_dir().join(format!("porp_assets_{}_{}", name, std::process::id())),
+        };
+        file.write(data);
+        file
+    }
+
+    fn write(&self, data: &[u8]) {
+        File::create(&self.path).expect("Unable to Create Temp File").write_all(data).expect("Unable to Write Temp File");
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = remove_file(&self.path);
+    }
+}
+
+fn textures_uploaded_to(log: &HeadlessLog, id: ID) -> usize {
+    log.get_uploads().iter().filter(|upload| match **upload { Upload::Texture(upload_id, _) => upload_id == id, _ => false }).count()
+}
+
+#[test]
+fn identical_files_share_one_texture() {
+    let manager = init();
+    let backend = HeadlessBackend::new(64, 64);
+    let log = backend.get_log();
+    let mut window = Window::from_backend(Box::new(backend));
+    let a = TempFile::new("share_a", &png(2, 2, [255, 0, 0, 255]));
+    let b = TempFile::new("share_b", &png(2, 2, [255, 0, 0, 255]));
+    let first = Arc::new(RwLock::new(Entity::new(manager.clone())));
+    let second = Arc::new(RwLock::new(Entity::new(manager.clone())));
+    let first_id = window.load_texture(&first, &a.path).expect("Unable to Load First Texture");
+    let second_id = window.load_texture(&second, &b.path).expect("Unable to Load Second Texture");
+    assert!(first_id == second_id);
+    assert_eq!(textures_uploaded_to(&log.read().expect("Unable to Read Log in Test"), first_id), 1);
+}
+
+#[test]
+fn reloading_a_shared_file_copies_on_write() {
+    let manager = init();
+    let backend = HeadlessBackend::new(64, 64);
+    let log = backend.get_log();
+    let mut window = Window::from_backend(Box::new(backend));
+    let a = TempFile::new("cow_a", &png(2, 2, [0, 255, 0, 255]));
+    let b = TempFile::new("cow_b", &png(2, 2, [0, 255, 0, 255]));
+    let edited = Arc::new(RwLock::new(Entity::new(manager.clone())));
+    let untouched = Arc::new(RwLock::new(Entity::new(manager.clone())));
+    let shared_id = window.load_texture(&edited, &a.path).expect("Unable to Load Edited Texture");
+    window.load_texture(&untouched, &b.path).expect("Unable to Load Untouched Texture");
+
+    thread::sleep(Duration::from_millis(20));
+    a.write(&png(4, 4, [0, 0, 255, 255]));
+    window.set_hot_reload(true);
+    assert!(window.reload_assets().is_empty());
+
+    let edited_id = edited.read().expect("Unable to Read Entity in Test").get_texture_id();
+    assert!(edited_id != shared_id);
+    assert!(untouched.read().expect("Unable to Read Entity in Test").get_texture_id() == shared_id);
+    {
+        let log = log.read().expect("Unable to Read Log in Test");
+        assert_eq!(textures_uploaded_to(&log, shared_id), 1);
+        assert!(log.get_uploads().iter().any(|upload| match *upload { Upload::Texture(id, dimensions) => id == edited_id && dimensions == (4, 4), _ => false }));
+    }
+
+    let original = TempFile::new("cow_c", &png(2, 2, [0, 255, 0, 255]));
+    let changed = TempFile::new("cow_d", &png(4, 4, [0, 0, 255, 255]));
+    let third = Arc::new(RwLock::new(Entity::new(manager.clone())));
+    let fourth = Arc::new(RwLock::new(Entity::new(manager.clone())));
+    assert!(window.load_texture(&third, &original.path).expect("Unable to Load Original Texture") == shared_id);
+    assert!(window.load_texture(&fourth, &changed.path).expect("Unable to Load Changed Texture") == edited_id);
+}
+
+#[test]
+fn reload_reports_decode_errors() {
+    let manager = init();
+    let mut window = Window::from_backend(Box::new(HeadlessBackend::new(64, 64)));
+    let file = TempFile::new("decode", &png(2, 2, [1, 2, 3, 4]));
+    let entity = Arc::new(RwLock::new(Entity::new(manager.clone())));
+    let id = window.load_texture(&entity, &file.path).expect("Unable to Load Texture");
+
+    thread::sleep(Duration::from_millis(20));
+    file.write(b"not an image");
+    window.set_hot_reload(true);
+    let errors = window.reload_assets();
+    assert_eq!(errors.len(), 1);
+    match errors[0] {
+        AssetError::Decode(ref path, _) => assert_eq!(*path, file.path),
+        ref err => panic!("Unexpected Error {}", err),
+    }
+    assert!(entity.read().expect("Unable to Read Entity in Test").get_texture_id() == id);
+}
+
+#[test]
+fn being_render_returns_texture_load_errors() {
+    let manager = init();
+    let mut window = Window::from_backend(Box::new(HeadlessBackend::new(64, 64)));
+    let missing = env::temp_dir().join(format!("porp_assets_missing_{}", std::process::id()));
+    let mut entities = HashMap::new();
+    entities.insert(0, Arc::new(RwLock::new(Entity::new(manager.clone()))));
+    let mut render_updates = RenderUpdateData::new();
+    render_updates.push_texture_path(0, missing.clone());
+    let mut sprite = TestBeing::new(TestType::Sprite).with_entities(entities).with_render_updates(render_updates);
+
+    let errors = sprite.render(&mut window);
+    assert_eq!(errors.len(), 1);
+    match errors[0] {
+        AssetError::Io(ref path, _) => assert_eq!(*path, missing),
+        ref err => panic!("Unexpected Error {}", err),
+    }
+    assert!(sprite.render(&mut window).is_empty());
+}
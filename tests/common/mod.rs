@@ -0,0 +1,109 @@
+#![allow(dead_code)]
+
+use std::collections::{HashMap};
+use std::sync::{Arc, RwLock};
+use porp::{init, Game, Being, BeingType, Entity, World, Transforms, IDManager, Vec2, Vec3, RenderUpdateData};
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TestType {
+    Quad,
+    Counter,
+    Sprite,
+    Probe,
+}
+
+impl BeingType for TestType {}
+
+pub struct TestBeing {
+    being_type: TestType,
+    entities: HashMap<u64, Arc<RwLock<Entity>>>,
+    render_updates: Arc<RwLock<RenderUpdateData>>,
+    position: Vec3,
+    runs_while_paused: bool,
+    layer: i32,
+    on_tick: Option<Box<FnMut(&World<TestType>) + Send + Sync>>,
+}
+
+impl TestBeing {
+    pub fn new(being_type: TestType) -> TestBeing {
+        TestBeing {
+            being_type: being_type,
+            entities: HashMap::new(),
+            render_updates: Arc::new(RwLock::new(RenderUpdateData::new())),
+            position: Vec3::zero(),
+            runs_while_paused: false,
+            layer: 0,
+            on_tick: None,
+        }
+    }
+
+    pub fn with_entities(mut self, entities: HashMap<u64, Arc<RwLock<Entity>>>) -> TestBeing {
+        self.entities = entities;
+        self
+    }
+
+    pub fn with_render_updates(mut self, render_updates: RenderUpdateData) -> TestBeing {
+        self.render_updates = Arc::new(RwLock::new(render_updates));
+        self
+    }
+
+    pub fn with_position(mut self, position: Vec3) -> TestBeing {
+        self.position = position;
+        self
+    }
+
+    pub fn with_runs_while_paused(mut self, runs_while_paused: bool) -> TestBeing {
+        self.runs_while_paused = runs_while_paused;
+        self
+    }
+
+    pub fn with_layer(mut self, layer: i32) -> TestBeing {
+        self.layer = layer;
+        self
+    }
+
+    pub fn with_tick<F: FnMut(&World<TestType>) + Send + Sync + 'static>(mut self, on_tick: F) -> TestBeing {
+        self.on_tick = Some(Box::new(on_tick));
+        self
+    }
+}
+
+impl Being<TestType> for TestBeing {
+    fn get_entities(&self) -> &HashMap<u64, Arc<RwLock<Entity>>> {
+        &self.entities
+    }
+
+    fn get_render_updates(&self) -> Arc<RwLock<RenderUpdateData>> {
+        self.render_updates.clone()
+    }
+
+    fn tick_prep(&self, _: &f32, _: &World<TestType>, _: &Transforms) {
+
+    }
+
+    fn tick(&mut self, world: Arc<RwLock<World<TestType>>>, _: Arc<RwLock<Transforms>>, _: Arc<RwLock<IDManager>>) {
+        if let Some(ref mut on_tick) = self.on_tick {
+            on_tick(&world.read().expect("Unable to Read World in Tick in Test Being"));
+        }
+    }
+
+    fn runs_while_paused(&self) -> bool {
+        self.runs_while_paused
+    }
+
+    fn get_layer(&self) -> i32 {
+        self.layer
+    }
+
+    fn get_type(&self) -> TestType {
+        self.being_type
+    }
+
+    fn get_position(&self) -> Vec3 {
+        self.position
+    }
+}
+
+pub fn new_game() -> Game<TestType> {
+    Game::<TestType>::new(init(), 2, Vec2::from([640.0, 480.0]))
+}
@@ -0,0 +1,62 @@
+extern crate porp;
+
+use std::sync::{Arc, RwLock};
+use porp::{ContextId, KeyCode, MouseButton};
+use common::{TestType, TestBeing, new_game};
+
+mod common;
+
+#[derive(Clone, Default, Debug, PartialEq)]
+struct Seen {
+    events: usize,
+    key_presses: usize,
+    mouse_presses: usize,
+    text: String,
+}
+
+fn probe(context: ContextId, seen: Arc<RwLock<Vec<(Seen, Seen)>>>) -> Box<TestBeing> {
+    Box::new(TestBeing::new(TestType::Probe).with_tick(move |world| {
+        let all = Seen {
+            events: world.get_input_events().len(),
+            key_presses: world.count_key_presses(KeyCode::A),
+            mouse_presses: world.count_mouse_presses(MouseButton::Left),
+            text: world.get_text_input(),
+        };
+        let mine = Seen {
+            events: world.get_input_events_in(Some(context)).len(),
+            key_presses: world.count_key_presses_in(Some(context), KeyCode::A),
+            mouse_presses: world.count_mouse_presses_in(Some(context), MouseButton::Left),
+            text: world.get_text_input_in(Some(context)),
+        };
+        seen.write().expect("Unable to Write Seen in Tick in Probe").push((all, mine));
+    }))
+}
+
+#[test]
+fn event_queries_respect_the_context_stack() {
+    let mut game = new_game();
+    let seen = Arc::new(RwLock::new(vec!()));
+    {
+        let world = game.get_world();
+        let world = world.read().expect("Unable to Read World in Test");
+        let menu = world.push_context("menu");
+        let top = world.push_context("popup");
+        world.with_context(top, |context| context.capture_all_keys(true));
+        world.spawn(probe(menu, seen.clone()));
+        world.spawn(probe(top, seen.clone()));
+    }
+    game.press_key(0, KeyCode::A);
+    game.type_text(0, "hi");
+    game.press_mouse(0, MouseButton::Left);
+    game.step(1);
+
+    let seen = seen.read().expect("Unable to Read Seen in Test");
+    assert_eq!(seen.len(), 2);
+    let everything = Seen { events: 4, key_presses: 1, mouse_presses: 1, text: "hi".to_string() };
+    let mouse_only = Seen { events: 1, key_presses: 0, mouse_presses: 1, text: String::new() };
+    for entry in seen.iter() {
+        assert_eq!(entry.0, mouse_only);
+    }
+    assert!(seen.iter().any(|entry| entry.1 == everything));
+    assert!(seen.iter().any(|entry| entry.1 == mouse_only));
+}
@@ -0,0 +1,267 @@
+extern crate porp;
+extern crate image;
+
+use std::collections::{HashMap};
+use std::sync::{Arc, RwLock};
+use image::{RgbaImage};
+use porp::{init, Window, Game, HeadlessBackend, HeadlessLog, Upload, Being, BeingId, Entity, Transforms, IDManager, IDType, Vec3, Mat4, RenderUpdateData, AtlasBuilder, Atlas, Vertex, DrawMethod, Camera, WindowEvent};
+use common::{TestType, TestBeing};
+
+mod common;
+
+const ENTITY_QUAD: u64 = 0;
+
+fn quad(manager: Arc<RwLock<IDManager>>, transforms: &Transforms, position: Vec3, atlas: &Atlas, camera: &Camera) -> TestBeing {
+    let entity = Arc::new(RwLock::new(Entity::new(manager)));
+    entity.write().expect("Unable to Write Entity in Quad").use_id(atlas.get_texture_id(), IDType::Texture);
+    camera.attach(&entity);
+    transforms.set_model_matrix(&entity, Mat4::translation_from_vec3(position));
+    let mut render_updates = RenderUpdateData::new();
+    render_updates.push_vertices(ENTITY_QUAD, atlas.map_vertices("quad", vec!(
+        Vertex::new([0.0, 0.0, 0.0], [0.0, 0.0]),
+        Vertex::new([1.0, 0.0, 0.0], [1.0, 0.0]),
+        Vertex::new([1.0, 1.0, 0.0], [1.0, 1.0]),
+        Vertex::new([0.0, 1.0, 0.0], [0.0, 1.0]),
+    )).expect("Unable to Map Vertices in Quad"));
+    render_updates.push_indices(ENTITY_QUAD, vec!(0, 1, 2, 2, 3, 0));
+    render_updates.push_draw_method(ENTITY_QUAD, DrawMethod::Neither);
+    let mut entities = HashMap::new();
+    entities.insert(ENTITY_QUAD, entity);
+    TestBeing::new(TestType::Quad).with_entities(entities).with_render_updates(render_updates).with_position(position)
+}
+
+fn quad_from(manager: Arc<RwLock<IDManager>>, transforms: &Transforms, position: Vec3, base: &TestBeing) -> TestBeing {
+    let mut entities = HashMap::new();
+    for entry in base.get_entities().iter() {
+        let mut entity = Entity::new_from(entry.1);
+        entity.use_new_id(manager.clone(), IDType::Model);
+        let entity = Arc::new(RwLock::new(entity));
+        transforms.set_model_matrix(&entity, Mat4::translation_from_vec3(position));
+        entities.insert(*entry.0, entity);
+    }
+    TestBeing::new(TestType::Quad).with_entities(entities).with_position(position)
+}
+
+struct Scene {
+    manager: Arc<RwLock<IDManager>>,
+    window: Window,
+    game: Game<TestType>,
+    log: Arc<RwLock<HeadlessLog>>,
+    ids: Vec<BeingId>,
+    atlas: Arc<Atlas>,
+    camera: Arc<RwLock<Camera>>,
+}
+
+fn build_scene(count: usize) -> Scene {
+    let manager = init();
+    let backend = HeadlessBackend::new(640, 480);
+    let log = backend.get_log();
+    let mut window = Window::from_backend(Box::new(backend));
+    let resolution = window.get_resolution_vec2();
+    let mut game = Game::<TestType>::new(manager.clone(), 2, resolution);
+    let mut builder = AtlasBuilder::new(1, 64);
+    builder.add_image("quad", RgbaImage::new(4, 4)).expect("Unable to Add Image in Build Scene");
+    let atlas = window.upload_atlas(manager.clone(), builder);
+    let camera = Arc::new(RwLock::new(Camera::orthographic(manager.clone(), 0.1, 100.0, 10.0, resolution)));
+    game.add_camera(camera.clone());
+    let mut ids = vec!();
+    {
+        let world = game.get_world();
+        let world = world.read().expect("Unable to Read World in Build Scene");
+        let transforms = game.get_transforms();
+        let transforms = transforms.read().expect("Unable to Read Transforms in Build Scene");
+        let camera = camera.read().expect("Unable to Read Camera in Build Scene");
+        let base = quad(manager.clone(), &transforms, Vec3::zero(), &atlas, &camera);
+        for i in 1..count {
+            ids.push(world.spawn(Box::new(quad_from(manager.clone(), &transforms, Vec3::from([i as f32, 0.0, 0.0]), &base))));
+        }
+        ids.push(world.spawn(Box::new(base)));
+    }
+    Scene {
+        manager: manager,
+        window: window,
+        game: game,
+        log: log,
+        ids: ids,
+        atlas: atlas,
+        camera: camera,
+    }
+}
+
+#[test]
+fn render_uploads_buffers_and_draws_shared_entities_in_one_batch() {
+    let mut scene = build_scene(3);
+    scene.game.step(1);
+    scene.game.render(&mut scene.window);
+
+    let log = scene.log.read().expect("Unable to Read Log in Test");
+    let uploads = log.get_uploads();
+    assert!(uploads.iter().any(|upload| match *upload { Upload::Texture(_, dimensions) => dimensions.0 >= 4 && dimensions.1 >= 4, _ => false }));
+    assert_eq!(uploads.iter().filter(|upload| match **upload { Upload::Vertices(_, ref vertices) => vertices.len() == 4, _ => false }).count(), 1);
+    assert_eq!(uploads.iter().filter(|upload| match **upload { Upload::Indices(_, ref indices) => indices.len() == 6, _ => false }).count(), 1);
+    assert_eq!(uploads.iter().filter(|upload| match **upload { Upload::DrawParameters(_) => true, _ => false }).count(), 1);
+
+    assert_eq!(log.get_frame_count(), 1);
+    let frame = log.get_last_frame().expect("Unable to Get Last Frame in Test");
+    assert_eq!(frame.len(), 1);
+    assert_eq!(frame[0].get_instance_count(), 3);
+    let mut offsets: Vec<f32> = frame[0].get_models().iter().map(|model| model.get_translation()[0]).collect();
+    offsets.sort_by(|a, b| a.partial_cmp(b).expect("Unable to Compare Offsets in Test"));
+    assert_eq!(offsets, vec!(0.0, 1.0, 2.0));
+}
+
+fn released_vertices(log: &HeadlessLog) -> usize {
+    log.get_uploads().iter().filter(|upload| match **upload { Upload::Release(IDType::Vertex, _) => true, _ => false }).count()
+}
+
+#[test]
+fn render_releases_shared_buffers_after_the_last_entity_is_gone() {
+    let mut scene = build_scene(2);
+    scene.game.step(1);
+    scene.game.render(&mut scene.window);
+    let world = scene.game.get_world();
+
+    world.read().expect("Unable to Read World in Test").despawn(scene.ids[0]);
+    scene.game.step(1);
+    scene.game.render(&mut scene.window);
+    assert_eq!(released_vertices(&scene.log.read().expect("Unable to Read Log in Test")), 0);
+    assert_eq!(scene.log.read().expect("Unable to Read Log in Test").get_last_frame().expect("Unable to Get Last Frame in Test")[0].get_instance_count(), 1);
+
+    world.read().expect("Unable to Read World in Test").despawn(scene.ids[1]);
+    scene.game.step(1);
+    scene.game.render(&mut scene.window);
+    let log = scene.log.read().expect("Unable to Read Log in Test");
+    assert_eq!(released_vertices(&log), 1);
+    assert!(log.get_last_frame().expect("Unable to Get Last Frame in Test").is_empty());
+}
+
+#[test]
+fn stepping_without_rendering_drains_released_ids() {
+    let mut scene = build_scene(2);
+    let world = scene.game.get_world();
+    for id in scene.ids.iter() {
+        world.read().expect("Unable to Read World in Test").despawn(*id);
+    }
+    scene.game.step(1);
+    assert!(scene.manager.write().expect("Unable to Write Manager in Test").take_released().is_empty());
+}
+
+#[test]
+fn buffers_uploaded_and_released_before_the_first_render_are_freed() {
+    let mut scene = build_scene(1);
+    let world = scene.game.get_world();
+    let being = world.read().expect("Unable to Read World in Test").get_being(scene.ids[0]).expect("Unable to Get Being in Test");
+    being.write().expect("Unable to Write Being in Test").render(&mut scene.window);
+    let vertex_id = being.read().expect("Unable to Read Being in Test").get_entities()[&ENTITY_QUAD].read().expect("Unable to Read Entity in Test").get_vertex_id();
+    drop(being);
+
+    world.read().expect("Unable to Read World in Test").despawn(scene.ids[0]);
+    scene.game.step(1);
+    assert_eq!(released_vertices(&scene.log.read().expect("Unable to Read Log in Test")), 0);
+    scene.game.render(&mut scene.window);
+    let log = scene.log.read().expect("Unable to Read Log in Test");
+    assert!(log.get_uploads().iter().any(|upload| match *upload { Upload::Release(IDType::Vertex, id) => id == vertex_id, _ => false }));
+    assert_eq!(released_vertices(&log), 1);
+}
+
+#[test]
+fn render_batches_interleaved_tiles_by_layer_and_buffers() {
+    let mut scene = build_scene(1);
+    {
+        let world = scene.game.get_world();
+        let world = world.read().expect("Unable to Read World in Test");
+        let transforms = scene.game.get_transforms();
+        let transforms = transforms.read().expect("Unable to Read Transforms in Test");
+        let camera = scene.camera.read().expect("Unable to Read Camera in Test");
+        let bases = vec!(
+            quad(scene.manager.clone(), &transforms, Vec3::from([100.0, 0.0, 0.0]), &scene.atlas, &camera),
+            quad(scene.manager.clone(), &transforms, Vec3::from([200.0, 0.0, 0.0]), &scene.atlas, &camera),
+            quad(scene.manager.clone(), &transforms, Vec3::from([300.0, 0.0, 0.0]), &scene.atlas, &camera).with_layer(-1),
+        );
+        for i in 1..100 {
+            for base in bases.iter() {
+                let position = base.get_position() + Vec3::from([i as f32 * 0.5, 0.0, 0.0]);
+                world.spawn(Box::new(quad_from(scene.manager.clone(), &transforms, position, base).with_layer(base.get_layer())));
+            }
+        }
+        for base in bases {
+            world.spawn(Box::new(base));
+        }
+    }
+    scene.game.step(1);
+    scene.game.render(&mut scene.window);
+
+    let log = scene.log.read().expect("Unable to Read Log in Test");
+    let frame = log.get_last_frame().expect("Unable to Get Last Frame in Test");
+    assert_eq!(frame.len(), 4);
+    let mut bands: Vec<(u32, usize)> = frame.iter().map(|draw_call| {
+        let band = (draw_call.get_models()[0].get_translation()[0] / 100.0).floor() as u32;
+        assert!(draw_call.get_models().iter().all(|model| (model.get_translation()[0] / 100.0).floor() as u32 == band));
+        (band, draw_call.get_instance_count())
+    }).collect();
+    assert_eq!(bands[0], (3, 100));
+    bands.sort();
+    assert_eq!(bands, vec!((0, 1), (1, 100), (2, 100), (3, 100)));
+}
+
+#[test]
+fn refresh_requests_a_redraw_until_the_next_render() {
+    let mut scene = build_scene(1);
+    scene.game.inject_event(0, WindowEvent::Refresh);
+    scene.game.step(1);
+    let world = scene.game.get_world();
+    assert!(world.read().expect("Unable to Read World in Test").is_redraw_requested());
+    scene.game.render(&mut scene.window);
+    assert!(!world.read().expect("Unable to Read World in Test").is_redraw_requested());
+}
+
+#[test]
+fn frame_keeps_draw_order_and_only_merges_adjacent_draws() {
+    let manager = init();
+    let backend = HeadlessBackend::new(640, 480);
+    let log = backend.get_log();
+    let mut window = Window::from_backend(Box::new(backend));
+    let resolution = window.get_resolution_vec2();
+    let mut builder = AtlasBuilder::new(1, 64);
+    builder.add_image("quad", RgbaImage::new(4, 4)).expect("Unable to Add Image in Test");
+    let atlas = window.upload_atlas(manager.clone(), builder);
+    let mut camera = Camera::orthographic(manager.clone(), 0.1, 100.0, 10.0, resolution);
+    let transforms = Arc::new(RwLock::new(Transforms::new()));
+    let mut entities = vec!();
+    let other;
+    {
+        let transforms = transforms.read().expect("Unable to Read Transforms in Test");
+        camera.update(&transforms, resolution);
+        let mut base = quad(manager.clone(), &transforms, Vec3::zero(), &atlas, &camera);
+        base.render(&mut window);
+        let first = base.get_entities()[&ENTITY_QUAD].clone();
+        for i in 0..4 {
+            let mut entity = Entity::new_from(&first);
+            entity.use_new_id(manager.clone(), IDType::Model);
+            let entity = Arc::new(RwLock::new(entity));
+            transforms.set_model_matrix(&entity, Mat4::translation_from_vec3(Vec3::from([i as f32, 0.0, 0.0])));
+            entities.push(entity);
+        }
+        let mut entity = Entity::new_from(&first);
+        entity.use_new_id(manager.clone(), IDType::Model);
+        entity.use_new_id(manager.clone(), IDType::View);
+        let entity = Arc::new(RwLock::new(entity));
+        transforms.set_view_matrix(&entity, Mat4::identity());
+        transforms.set_model_matrix(&entity, Mat4::translation_from_vec3(Vec3::from([9.0, 0.0, 0.0])));
+        other = entity;
+    }
+    {
+        let mut frame = window.frame();
+        frame.draw_entity(&entities[0], &transforms);
+        frame.draw_entity(&entities[1], &transforms);
+        frame.draw_entity(&other, &transforms);
+        frame.draw_entity(&entities[2], &transforms);
+        frame.draw_entity(&entities[3], &transforms);
+        frame.end();
+    }
+
+    let log = log.read().expect("Unable to Read Log in Test");
+    let frame = log.get_last_frame().expect("Unable to Get Last Frame in Test");
+    let offsets: Vec<Vec<f32>> = frame.iter().map(|draw_call| draw_call.get_models().iter().map(|model| model.get_translation()[0]).collect()).collect();
+    assert_eq!(offsets, vec!(vec!(0.0, 1.0), vec!(9.0), vec!(2.0, 3.0)));
+}
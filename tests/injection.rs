@@ -0,0 +1,63 @@
+extern crate porp;
+
+use porp::{KeyCode, MouseButton, WindowEvent, Vec2};
+use common::{new_game};
+
+mod common;
+
+#[test]
+fn pressed_key_is_just_pressed_on_its_tick() {
+    let mut game = new_game();
+    game.press_key(0, KeyCode::Space);
+    game.step(1);
+    let world = game.get_world();
+    let world = world.read().expect("Unable to Read World in Test");
+    let key = world.get_key(KeyCode::Space);
+    assert!(key.is_pressed());
+    assert!(key.just_pressed(0));
+}
+
+#[test]
+fn injected_events_wait_for_their_tick() {
+    let mut game = new_game();
+    game.click_mouse(2, MouseButton::Left);
+    game.inject_event(1, WindowEvent::Resized(800, 600));
+    game.step(2);
+    {
+        let world = game.get_world();
+        let world = world.read().expect("Unable to Read World in Test");
+        assert!(!world.get_mouse_button(MouseButton::Left).is_pressed());
+        assert_eq!(world.get_resolution(), Vec2::from([800.0, 600.0]));
+    }
+    game.step(1);
+    let world = game.get_world();
+    let world = world.read().expect("Unable to Read World in Test");
+    assert!(world.get_mouse_button(MouseButton::Left).just_pressed(2));
+}
+
+#[test]
+fn injecting_into_a_past_tick_applies_on_the_next_step() {
+    let mut game = new_game();
+    game.step(3);
+    assert_eq!(game.get_tick_number(), 3);
+    assert_eq!(game.get_world().read().expect("Unable to Read World in Test").get_tick_number(), 2);
+    game.press_key(1, KeyCode::Space);
+    game.step(1);
+    let world = game.get_world();
+    let world = world.read().expect("Unable to Read World in Test");
+    assert_eq!(world.get_tick_number(), 3);
+    assert!(world.get_key(KeyCode::Space).just_pressed(3));
+}
+
+#[test]
+fn events_injected_at_the_game_tick_number_apply_on_the_next_step() {
+    let mut game = new_game();
+    game.step(5);
+    let tick_number = game.get_tick_number();
+    game.press_key(tick_number, KeyCode::Space);
+    game.step(1);
+    let world = game.get_world();
+    let world = world.read().expect("Unable to Read World in Test");
+    assert_eq!(world.get_tick_number(), tick_number);
+    assert!(world.key_just_pressed(KeyCode::Space));
+}
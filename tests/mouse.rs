@@ -0,0 +1,49 @@
+extern crate porp;
+
+use std::sync::{Arc, RwLock};
+use porp::{init, Game, Camera, Entity, Window, HeadlessBackend, CursorState, Mat4, Vec2, Vec3};
+use common::{TestType};
+
+mod common;
+
+fn assert_near(a: Vec3, b: Vec3) {
+    for i in 0..3 {
+        assert!((a[i] - b[i]).abs() < 1e-4, "{} != {}", a[i], b[i]);
+    }
+}
+
+#[test]
+fn unproject_mouse_ignores_the_model_matrix() {
+    let manager = init();
+    let resolution = Vec2::from([640.0, 480.0]);
+    let mut game = Game::<TestType>::new(manager.clone(), 2, resolution);
+    let camera = Arc::new(RwLock::new(Camera::orthographic(manager.clone(), 0.1, 100.0, 10.0, resolution)));
+    game.add_camera(camera.clone());
+    let entity = Arc::new(RwLock::new(Entity::new(manager.clone())));
+    camera.read().expect("Unable to Read Camera in Test").attach(&entity);
+    let transforms = game.get_transforms();
+    transforms.read().expect("Unable to Read Transforms in Test").set_model_matrix(&entity, Mat4::translation_from_vec3(Vec3::from([100.0, 0.0, 0.0])));
+    game.move_mouse(0, Vec2::from([480.0, 120.0]));
+    game.step(1);
+
+    let world = game.get_world();
+    let world = world.read().expect("Unable to Read World in Test");
+    let camera = camera.read().expect("Unable to Read Camera in Test");
+    for depth in [-1.0, 0.0, 1.0].iter() {
+        let unprojected = world.unproject_mouse(&transforms.read().expect("Unable to Read Transforms in Test"), &entity.read().expect("Unable to Read Entity in Test"), *depth).expect("Unable to Unproject Mouse in Test");
+        assert_near(unprojected, world.get_mouse_world(&camera, *depth).expect("Unable to Get Mouse World in Test"));
+    }
+}
+
+#[test]
+fn render_applies_cursor_state_changes() {
+    let manager = init();
+    let backend = HeadlessBackend::new(640, 480);
+    let log = backend.get_log();
+    let mut window = Window::from_backend(Box::new(backend));
+    let mut game = Game::<TestType>::new(manager, 2, window.get_resolution_vec2());
+    game.get_world().read().expect("Unable to Read World in Test").set_cursor_state(CursorState::Grab);
+    game.render(&mut window);
+    assert_eq!(log.read().expect("Unable to Read Log in Test").get_cursor_state(), CursorState::Grab);
+    assert!(game.take_cursor_error().is_none());
+}
@@ -0,0 +1,125 @@
+extern crate porp;
+
+use std::env;
+use std::fs::{File, remove_file};
+use std::io::{Read};
+use std::path::{PathBuf};
+use porp::{Game, BeingId, KeyCode, Vec3};
+use common::{TestType, TestBeing, new_game};
+
+mod common;
+
+struct TempPath {
+    path: PathBuf,
+}
+
+impl TempPath {
+    fn new(name: &str) -> TempPath {
+        TempPath {
+            path: env::temp_dir().join(format!("porp_recording_{}_{}", name, std::process::id())),
+        }
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        let _ = remove_file(&self.path);
+    }
+}
+
+fn key_history(game: &mut Game<TestType>, ticks: u64) -> Vec<(bool, bool, bool)> {
+    let mut history = vec!();
+    for _ in 0..ticks {
+        game.step(1);
+        let world = game.get_world();
+        let world = world.read().expect("Unable to Read World in Test");
+        let key = world.get_key(KeyCode::Mute);
+        let tick_number = world.get_tick_number();
+        history.push((key.is_pressed(), key.just_pressed(tick_number), key.just_released(tick_number)));
+    }
+    history
+}
+
+fn spawner(salt: u64) -> Box<TestBeing> {
+    Box::new(TestBeing::new(TestType::Probe).with_tick(move |world| {
+        if world.key_just_pressed(KeyCode::Mute) {
+            world.queue_spawn(Box::new(TestBeing::new(TestType::Counter).with_position(Vec3::from([salt as f32, 0.0, 0.0]))));
+        }
+    }))
+}
+
+fn spawning_game() -> Game<TestType> {
+    let game = new_game();
+    for salt in 0..8 {
+        game.get_world().read().expect("Unable to Read World in Spawning Game").spawn(spawner(salt));
+    }
+    game
+}
+
+fn world_history(game: &mut Game<TestType>, ticks: u64) -> Vec<Vec<(BeingId, TestType, [f32; 3])>> {
+    let mut history = vec!();
+    for _ in 0..ticks {
+        game.step(1);
+        let world = game.get_world();
+        let beings = world.read().expect("Unable to Read World in Test").get_beings();
+        let mut state: Vec<(BeingId, TestType, [f32; 3])> = beings.read().expect("Unable to Read Beings in Test").iter().map(|entry| {
+            let being = entry.1.read().expect("Unable to Read Being in Test");
+            (*entry.0, being.get_type(), being.get_position().get_vals())
+        }).collect();
+        state.sort_by_key(|entry| entry.0);
+        history.push(state);
+    }
+    history
+}
+
+#[test]
+fn replay_reproduces_world_state_tick_for_tick() {
+    let file = TempPath::new("world");
+    let mut recorded = spawning_game();
+    recorded.step(2);
+    recorded.start_recording(&file.path).expect("Unable to Start Recording in Test");
+    recorded.tap_key(3, KeyCode::Mute);
+    recorded.tap_key(6, KeyCode::Mute);
+    let expected = world_history(&mut recorded, 7);
+    recorded.stop_recording().expect("Unable to Stop Recording in Test");
+    assert_eq!(expected.last().expect("Unable to Get Last State in Test").len(), 24);
+
+    let mut replayed = spawning_game();
+    replayed.start_replay(&file.path).expect("Unable to Start Replay in Test");
+    assert_eq!(world_history(&mut replayed, 7), expected);
+}
+
+#[test]
+fn recorded_input_replays_on_the_same_ticks() {
+    let file = TempPath::new("replay");
+    let mut recorded = new_game();
+    recorded.set_tick_rate(30.0).expect("Unable to Set Tick Rate in Test");
+    recorded.set_seed(42);
+    recorded.step(3);
+    recorded.start_recording(&file.path).expect("Unable to Start Recording in Test");
+    recorded.press_key(4, KeyCode::Mute);
+    recorded.release_key(6, KeyCode::Mute);
+    recorded.tap_key(7, KeyCode::Mute);
+    let expected = key_history(&mut recorded, 6);
+    recorded.stop_recording().expect("Unable to Stop Recording in Test");
+    assert!(recorded.take_recording_error().is_none());
+
+    let mut text = String::new();
+    File::open(&file.path).expect("Unable to Open Recording in Test").read_to_string(&mut text).expect("Unable to Read Recording in Test");
+    assert!(text.starts_with("tick_rate 30\nseed 42\n"));
+
+    let mut replayed = new_game();
+    replayed.start_replay(&file.path).expect("Unable to Start Replay in Test");
+    assert_eq!(replayed.get_tick_rate(), 30.0);
+    assert_eq!(replayed.get_seed(), 42);
+    assert!(replayed.is_replaying());
+    assert_eq!(key_history(&mut replayed, 6), expected);
+    assert!(!replayed.is_replaying());
+    assert_eq!(expected.iter().filter(|state| state.1).count(), 2);
+}
+
+#[test]
+fn replaying_a_missing_file_fails() {
+    let file = TempPath::new("missing");
+    assert!(new_game().start_replay(&file.path).is_err());
+}
@@ -0,0 +1,97 @@
+extern crate porp;
+
+use std::sync::{Arc};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use porp::{Game};
+use common::{TestType, TestBeing, new_game};
+
+mod common;
+
+fn counter(ticks: Arc<AtomicUsize>, unpause: bool) -> Box<TestBeing> {
+    Box::new(TestBeing::new(TestType::Counter).with_runs_while_paused(unpause).with_tick(move |world| {
+        ticks.fetch_add(1, Ordering::SeqCst);
+        if unpause {
+            world.set_paused(false);
+        }
+    }))
+}
+
+fn counting_game(tick_rate: f64) -> (Game<TestType>, Arc<AtomicUsize>) {
+    let mut game = new_game();
+    game.set_tick_rate(tick_rate).expect("Unable to Set Tick Rate in Counting Game");
+    let ticks = Arc::new(AtomicUsize::new(0));
+    game.get_world().read().expect("Unable to Read World in Counting Game").spawn(counter(ticks.clone(), false));
+    (game, ticks)
+}
+
+#[test]
+fn step_runs_exactly_n_ticks() {
+    let (mut game, ticks) = counting_game(60.0);
+    game.step(5);
+    assert_eq!(ticks.load(Ordering::SeqCst), 5);
+    assert_eq!(game.get_tick_number(), 5);
+    assert_eq!(game.get_world().read().expect("Unable to Read World in Test").get_tick_number(), 4);
+}
+
+#[test]
+fn advance_carries_the_remainder_over() {
+    let (mut game, ticks) = counting_game(4.0);
+    assert_eq!(game.advance(0.375), 1);
+    assert_eq!(game.advance(0.125), 1);
+    assert_eq!(game.advance(0.125), 0);
+    assert_eq!(game.advance(0.125), 1);
+    assert_eq!(ticks.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn advance_caps_catch_up_and_drops_the_backlog() {
+    let (mut game, ticks) = counting_game(4.0);
+    game.set_max_catch_up(3);
+    assert_eq!(game.advance(10.0), 3);
+    assert_eq!(game.advance(0.0), 0);
+    assert_eq!(game.advance(0.25), 1);
+    assert_eq!(ticks.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn zero_tick_rate_is_rejected() {
+    let (mut game, _) = counting_game(60.0);
+    assert_eq!(game.set_tick_rate(0.0), Err("Invalid Tick Rate 0".to_string()));
+    assert_eq!(game.get_tick_rate(), 60.0);
+}
+
+#[test]
+fn negative_and_non_finite_tick_rates_are_rejected() {
+    let (mut game, ticks) = counting_game(60.0);
+    assert_eq!(game.set_tick_rate(-30.0), Err("Invalid Tick Rate -30".to_string()));
+    assert!(game.set_tick_rate(::std::f64::INFINITY).is_err());
+    assert!(game.set_tick_rate(::std::f64::NAN).is_err());
+    assert_eq!(game.get_tick_rate(), 60.0);
+    game.step(2);
+    assert_eq!(ticks.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn unpausing_from_a_being_drops_the_paused_backlog() {
+    let (mut game, ticks) = counting_game(4.0);
+    game.get_world().read().expect("Unable to Read World in Test").spawn(counter(ticks.clone(), true));
+    game.pause();
+    assert_eq!(game.advance(0.375), 1);
+    assert!(!game.is_paused());
+    assert_eq!(ticks.load(Ordering::SeqCst), 1);
+    assert_eq!(game.advance(0.2), 0);
+    assert_eq!(game.advance(0.05), 1);
+    assert_eq!(ticks.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn unpausing_the_world_directly_drops_the_paused_backlog() {
+    let (mut game, ticks) = counting_game(4.0);
+    game.pause();
+    assert_eq!(game.advance(0.375), 1);
+    assert_eq!(ticks.load(Ordering::SeqCst), 0);
+    game.get_world().read().expect("Unable to Read World in Test").set_paused(false);
+    assert_eq!(game.advance(0.2), 0);
+    assert_eq!(game.advance(0.05), 1);
+    assert_eq!(ticks.load(Ordering::SeqCst), 1);
+}